@@ -0,0 +1,72 @@
+use pixieve_rs::image_proxy::config::ImageProxyConfig;
+use pixieve_rs::image_proxy::server::ImageProxy;
+
+use log::{LogLevel, LogLevelFilter, LogMetadata, LogRecord};
+
+const USAGE: &str = "Usage: pximg-proxy [--listen ADDR] [--upstream URL] [--cache-dir DIR] \
+                     [--max-entry-size BYTES] [--max-cache-size BYTES]";
+
+/// Prints warnings and errors, such as failed upstream requests or cache writes, to stderr.
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &LogMetadata) -> bool {
+        metadata.level() <= LogLevel::Warn
+    }
+
+    fn log(&self, record: &LogRecord) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}: {}", record.level(), record.args());
+        }
+    }
+}
+
+fn parse_size(value: &str) -> u64 {
+    value.parse().unwrap_or_else(|_| {
+        eprintln!("Invalid size: {}\n{}", value, USAGE);
+        std::process::exit(2);
+    })
+}
+
+fn main() {
+    log::set_logger(|max_level| {
+        max_level.set(LogLevelFilter::Warn);
+        Box::new(StderrLogger)
+    })
+    .expect("No logger is set yet.");
+
+    let mut listen = String::from("127.0.0.1:8080");
+    let mut config = ImageProxyConfig::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        if flag == "--help" || flag == "-h" {
+            println!("{}", USAGE);
+            return;
+        }
+        let value = args.next().unwrap_or_else(|| {
+            eprintln!("Missing value for {}\n{}", flag, USAGE);
+            std::process::exit(2);
+        });
+        config = match flag.as_str() {
+            "--listen" => {
+                listen = value;
+                config
+            }
+            "--upstream" => config.set_upstream(value),
+            "--cache-dir" => config.set_cache_dir(value),
+            "--max-entry-size" => config.set_max_entry_size(parse_size(&value)),
+            "--max-cache-size" => config.set_max_cache_size(parse_size(&value)),
+            _ => {
+                eprintln!("Unknown argument: {}\n{}", flag, USAGE);
+                std::process::exit(2);
+            }
+        };
+    }
+
+    let proxy = ImageProxy::new(config).expect("Failed to set up the image proxy.");
+    println!("Serving images on http://{}", listen);
+    proxy
+        .bind_and_serve(listen.as_str())
+        .expect("Image proxy stopped.");
+}
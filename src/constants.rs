@@ -11,7 +11,9 @@ pub const X_CLIENT_HASH: &'static str = "X-Client-Hash";
 pub const FOR_IOS: &'static str = "for_ios";
 
 // Header Keys
+pub const COMMENT_ID: &str = "comment_id";
 pub const ILLUST_ID: &'static str = "illust_id";
+pub const NOVEL_ID: &str = "novel_id";
 pub const RESTRICT: &'static str = "restrict";
 pub const TAGS: &'static str = "tags[]";
 pub const OFFSET: &'static str = "offset";
@@ -19,3 +21,11 @@ pub const USER_ID: &'static str = "user_id";
 
 // Header Values
 pub const NONE: &'static str = "none";
+
+// The novel viewer page the app loads, the one that embeds the novel as JSON.
+pub const NOVEL_VIEWER_VERSION: &str = "20221031_ai";
+
+// Image hosting
+pub const PXIMG_HOST: &str = "https://i.pximg.net";
+pub const PIXIV_REFERER: &str = "https://www.pixiv.net/";
+pub const WEB_URL: &str = "https://www.pixiv.net";
@@ -1,3 +1,6 @@
+use crate::errors::RankingDateError;
+
+use chrono::{FixedOffset, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 // TODO: Specificy how serde should deserialize these...
@@ -6,6 +9,10 @@ pub enum SearchTarget {
     TagsPartial,
     TagsExact,
     TitleAndCaption,
+    /// Novel search only: the full text.
+    Text,
+    /// Novel search only: title, caption and tags.
+    Keyword,
 }
 
 impl SearchTarget {
@@ -14,6 +21,8 @@ impl SearchTarget {
             SearchTarget::TagsPartial => "partial_match_for_tags",
             SearchTarget::TagsExact => "exact_match_for_tags",
             SearchTarget::TitleAndCaption => "title_and_caption",
+            SearchTarget::Text => "text",
+            SearchTarget::Keyword => "keyword",
         }
     }
 
@@ -22,41 +31,89 @@ impl SearchTarget {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+/// Kind of work. Values pixiv adds later decode as `Unknown`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
 pub enum ContentType {
-    #[serde(rename = "illust")]
     Illustration,
-    #[serde(rename = "manga")]
     Manga,
-    #[serde(rename = "ugoira")]
     Ugoira,
-    #[serde(rename = "novel")]
     Novel,
+    Unknown(String),
 }
 
 impl ContentType {
-    pub fn as_str(&self) -> &'static str {
-        match *self {
+    pub fn as_str(&self) -> &str {
+        match self {
             ContentType::Illustration => "illust",
             ContentType::Manga => "manga",
             ContentType::Ugoira => "ugoira",
             ContentType::Novel => "novel",
+            ContentType::Unknown(value) => value,
         }
     }
 }
 
-/// Enum to set publicity param.
-#[derive(Debug, Clone, Copy)]
+impl From<String> for ContentType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "illust" => ContentType::Illustration,
+            "manga" => ContentType::Manga,
+            "ugoira" => ContentType::Ugoira,
+            "novel" => ContentType::Novel,
+            _ => ContentType::Unknown(value),
+        }
+    }
+}
+
+impl From<ContentType> for String {
+    fn from(value: ContentType) -> Self {
+        match value {
+            ContentType::Unknown(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+/// Enum to set publicity param, also used for who can see a profile field.
+/// Values pixiv adds later decode as `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Publicity {
     Public,
     Private,
+    /// Only the user's mypixiv friends.
+    MyPixiv,
+    Unknown(String),
 }
 
 impl Publicity {
-    pub fn as_str(&self) -> &'static str {
-        match *self {
+    pub fn as_str(&self) -> &str {
+        match self {
             Publicity::Public => "public",
             Publicity::Private => "private",
+            Publicity::MyPixiv => "mypixiv",
+            Publicity::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<String> for Publicity {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "public" => Publicity::Public,
+            "private" => Publicity::Private,
+            "mypixiv" => Publicity::MyPixiv,
+            _ => Publicity::Unknown(value),
+        }
+    }
+}
+
+impl From<Publicity> for String {
+    fn from(value: Publicity) -> Self {
+        match value {
+            Publicity::Unknown(value) => value,
+            known => known.as_str().to_string(),
         }
     }
 }
@@ -81,30 +138,33 @@ impl RankingType {
     }
 }
 
-/// Enum to set ranking mode param.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// Enum to set ranking mode param. Values pixiv adds later decode as `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum RankingMode {
-    #[serde(rename = "day")]
     Daily,
-    #[serde(rename = "week")]
     Weekly,
-    #[serde(rename = "month")]
     Monthly,
-    #[serde(rename = "day_male")]
     DayMale,
-    #[serde(rename = "day_female")]
     DayFemale,
-    #[serde(rename = "week_original")]
     WeekOriginal,
-    #[serde(rename = "week_rookie")]
     WeekRookie,
-    #[serde(rename = "day_manga")]
     DayManga,
+    /// R-18 modes need R-18 works to be enabled in the account's settings.
+    DayR18,
+    WeekR18,
+    WeekR18G,
+    DayMaleR18,
+    DayFemaleR18,
+    /// Works marked as AI-generated, which the other modes leave out.
+    DayAi,
+    DayR18Ai,
+    Unknown(String),
 }
 
 impl RankingMode {
-    pub fn as_str(&self) -> &'static str {
-        match *self {
+    pub fn as_str(&self) -> &str {
+        match self {
             RankingMode::Daily => "day",
             RankingMode::Weekly => "week",
             RankingMode::Monthly => "month",
@@ -113,10 +173,115 @@ impl RankingMode {
             RankingMode::WeekOriginal => "week_original",
             RankingMode::WeekRookie => "week_rookie",
             RankingMode::DayManga => "day_manga",
+            RankingMode::DayR18 => "day_r18",
+            RankingMode::WeekR18 => "week_r18",
+            RankingMode::WeekR18G => "week_r18g",
+            RankingMode::DayMaleR18 => "day_male_r18",
+            RankingMode::DayFemaleR18 => "day_female_r18",
+            RankingMode::DayAi => "day_ai",
+            RankingMode::DayR18Ai => "day_r18_ai",
+            RankingMode::Unknown(value) => value,
+        }
+    }
+
+    /// The first day pixiv has a ranking of this mode for.
+    pub fn earliest_date(&self) -> NaiveDate {
+        let (year, month, day) = match self {
+            RankingMode::Daily | RankingMode::Weekly | RankingMode::Monthly => (2007, 9, 13),
+            RankingMode::Unknown(_) => (2007, 9, 13),
+            RankingMode::DayManga => (2007, 9, 13),
+            RankingMode::WeekOriginal => (2008, 9, 27),
+            RankingMode::WeekRookie => (2010, 7, 13),
+            RankingMode::DayR18 | RankingMode::WeekR18 | RankingMode::WeekR18G => (2007, 9, 13),
+            RankingMode::DayMale | RankingMode::DayFemale => (2011, 5, 8),
+            RankingMode::DayMaleR18 | RankingMode::DayFemaleR18 => (2011, 5, 8),
+            RankingMode::DayAi | RankingMode::DayR18Ai => (2022, 11, 1),
+        };
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// Whether the mode ranks R-18 or R-18G works.
+    pub fn is_r18(&self) -> bool {
+        matches!(
+            self,
+            RankingMode::DayR18
+                | RankingMode::WeekR18
+                | RankingMode::WeekR18G
+                | RankingMode::DayMaleR18
+                | RankingMode::DayFemaleR18
+                | RankingMode::DayR18Ai
+        )
+    }
+
+    /// Every day from `start` to `end`, both included, that pixiv has a ranking of this mode for,
+    /// given that it is `today` in Japan.
+    pub fn dates_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        today: NaiveDate,
+    ) -> Vec<NaiveDate> {
+        // Today's ranking is only published tomorrow, see `validate_date`.
+        let end = match today.pred_opt() {
+            Some(yesterday) => end.min(yesterday),
+            None => return Vec::new(),
+        };
+        let start = start.max(self.earliest_date());
+        start.iter_days().take_while(|date| *date <= end).collect()
+    }
+
+    /// Check that pixiv has a ranking of this mode for `date`, given that it is `today` in Japan.
+    /// A day's ranking is only published the day after, so `today` itself has none yet.
+    pub fn validate_date(&self, date: NaiveDate, today: NaiveDate) -> Result<(), RankingDateError> {
+        let earliest = self.earliest_date();
+        if date >= today {
+            Err(RankingDateError::InFuture { date, today })
+        } else if date < earliest {
+            Err(RankingDateError::BeforeEarliest { date, earliest })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<String> for RankingMode {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "day" => RankingMode::Daily,
+            "week" => RankingMode::Weekly,
+            "month" => RankingMode::Monthly,
+            "day_male" => RankingMode::DayMale,
+            "day_female" => RankingMode::DayFemale,
+            "week_original" => RankingMode::WeekOriginal,
+            "week_rookie" => RankingMode::WeekRookie,
+            "day_manga" => RankingMode::DayManga,
+            "day_r18" => RankingMode::DayR18,
+            "week_r18" => RankingMode::WeekR18,
+            "week_r18g" => RankingMode::WeekR18G,
+            "day_male_r18" => RankingMode::DayMaleR18,
+            "day_female_r18" => RankingMode::DayFemaleR18,
+            "day_ai" => RankingMode::DayAi,
+            "day_r18_ai" => RankingMode::DayR18Ai,
+            _ => RankingMode::Unknown(value),
         }
     }
 }
 
+impl From<RankingMode> for String {
+    fn from(value: RankingMode) -> Self {
+        match value {
+            RankingMode::Unknown(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+/// The current date in Japan, which is what pixiv's ranking dates are relative to.
+pub fn pixiv_today() -> NaiveDate {
+    let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+    Utc::now().with_timezone(&jst).date_naive()
+}
+
 /// Enum to set search period param.
 #[derive(Debug, Clone, Copy)]
 pub enum SearchPeriod {
@@ -178,6 +343,10 @@ impl SearchOrder {
 pub enum SearchSort {
     DateAscending,
     DateDescending,
+    /// Most bookmarked first. The popularity sorts need a premium account.
+    PopularDescending,
+    PopularMaleDescending,
+    PopularFemaleDescending,
 }
 
 impl SearchSort {
@@ -185,6 +354,25 @@ impl SearchSort {
         match *self {
             SearchSort::DateAscending => "date_asc",
             SearchSort::DateDescending => "date_desc",
+            SearchSort::PopularDescending => "popular_desc",
+            SearchSort::PopularMaleDescending => "popular_male_desc",
+            SearchSort::PopularFemaleDescending => "popular_female_desc",
+        }
+    }
+}
+
+/// Whether a search includes works marked as AI-generated, sent as `search_ai_type`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchAiType {
+    Include,
+    Exclude,
+}
+
+impl SearchAiType {
+    pub fn value(&self) -> u32 {
+        match *self {
+            SearchAiType::Include => 0,
+            SearchAiType::Exclude => 1,
         }
     }
 }
@@ -206,19 +394,60 @@ impl Duration {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Which Pixivision articles to list.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpotlightCategory {
+    All,
+    Illustration,
+    Manga,
+    Cosplay,
+}
+
+impl SpotlightCategory {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SpotlightCategory::All => "all",
+            SpotlightCategory::Illustration => "illust",
+            SpotlightCategory::Manga => "manga",
+            SpotlightCategory::Cosplay => "cosplay",
+        }
+    }
+}
+
+/// Restriction of a bookmark or follow. Values pixiv adds later decode as `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Visibility {
-    #[serde(rename = "public")]
     Public,
-    #[serde(rename = "private")]
     Private,
+    Unknown(String),
 }
 
 impl Visibility {
-    pub fn as_str(&self) -> &'static str {
-        match *self {
+    pub fn as_str(&self) -> &str {
+        match self {
             Visibility::Public => "public",
             Visibility::Private => "private",
+            Visibility::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<String> for Visibility {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "public" => Visibility::Public,
+            "private" => Visibility::Private,
+            _ => Visibility::Unknown(value),
+        }
+    }
+}
+
+impl From<Visibility> for String {
+    fn from(value: Visibility) -> Self {
+        match value {
+            Visibility::Unknown(value) => value,
+            known => known.as_str().to_string(),
         }
     }
 }
@@ -244,3 +473,135 @@ impl Filter {
         }
     }
 }
+
+/// Whether a work was made with generative AI, from `illust_ai_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum AiType {
+    /// The author has not said. Older works all have this.
+    #[default]
+    Unspecified,
+    NotAiGenerated,
+    AiGenerated,
+    Unknown(u32),
+}
+
+impl AiType {
+    pub fn value(&self) -> u32 {
+        match *self {
+            AiType::Unspecified => 0,
+            AiType::NotAiGenerated => 1,
+            AiType::AiGenerated => 2,
+            AiType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<u32> for AiType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => AiType::Unspecified,
+            1 => AiType::NotAiGenerated,
+            2 => AiType::AiGenerated,
+            _ => AiType::Unknown(value),
+        }
+    }
+}
+
+impl From<AiType> for u32 {
+    fn from(value: AiType) -> Self {
+        value.value()
+    }
+}
+
+/// Age restriction of a work, from `x_restrict`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum XRestrict {
+    AllAges,
+    R18,
+    R18G,
+    Unknown(u32),
+}
+
+impl XRestrict {
+    pub fn value(&self) -> u32 {
+        match *self {
+            XRestrict::AllAges => 0,
+            XRestrict::R18 => 1,
+            XRestrict::R18G => 2,
+            XRestrict::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<u32> for XRestrict {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => XRestrict::AllAges,
+            1 => XRestrict::R18,
+            2 => XRestrict::R18G,
+            _ => XRestrict::Unknown(value),
+        }
+    }
+}
+
+impl From<XRestrict> for u32 {
+    fn from(value: XRestrict) -> Self {
+        value.value()
+    }
+}
+
+/// How sensitive pixiv rates a work, from `sanity_level`. Higher is more sensitive.
+/// Levels are ordered by their numeric value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum SanityLevel {
+    Unchecked,
+    Safe,
+    Suggestive,
+    Sensitive,
+    Unknown(u32),
+}
+
+impl SanityLevel {
+    pub fn value(&self) -> u32 {
+        match *self {
+            SanityLevel::Unchecked => 0,
+            SanityLevel::Safe => 2,
+            SanityLevel::Suggestive => 4,
+            SanityLevel::Sensitive => 6,
+            SanityLevel::Unknown(value) => value,
+        }
+    }
+}
+
+impl PartialOrd for SanityLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SanityLevel {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl From<u32> for SanityLevel {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => SanityLevel::Unchecked,
+            2 => SanityLevel::Safe,
+            4 => SanityLevel::Suggestive,
+            6 => SanityLevel::Sensitive,
+            _ => SanityLevel::Unknown(value),
+        }
+    }
+}
+
+impl From<SanityLevel> for u32 {
+    fn from(value: SanityLevel) -> Self {
+        value.value()
+    }
+}
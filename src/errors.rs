@@ -1,3 +1,8 @@
+use crate::pixiv::schema::SchemaReport;
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
 use std::error::Error;
 use std::fmt;
 
@@ -33,3 +38,200 @@ impl fmt::Display for AuthError {
         )
     }
 }
+
+/// Error returned when asking for a ranking on a date pixiv has no ranking for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankingDateError {
+    /// The ranking for this date has not been published yet.
+    InFuture { date: NaiveDate, today: NaiveDate },
+    /// The ranking mode did not exist yet on this date.
+    BeforeEarliest {
+        date: NaiveDate,
+        earliest: NaiveDate,
+    },
+}
+
+impl Error for RankingDateError {}
+
+impl fmt::Display for RankingDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RankingDateError::InFuture { date, today } => write!(
+                f,
+                "There is no ranking for {} yet. Today is {} in Japan.",
+                date, today
+            ),
+            RankingDateError::BeforeEarliest { date, earliest } => write!(
+                f,
+                "There is no ranking for {}. The earliest ranking for this mode is on {}.",
+                date, earliest
+            ),
+        }
+    }
+}
+
+/// Error returned on failure to decode a pixiv response into a model.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The request itself failed, or the body could not be read.
+    Http(reqwest::Error),
+    /// The body does not fit the model.
+    Json(serde_json::Error),
+    /// The body fits, but has fields the model does not know or lacks fields it expects.
+    /// Only returned in `SchemaMode::Strict`.
+    SchemaDrift(SchemaReport),
+    /// pixiv answered with an error status instead of a result.
+    Api(ApiError),
+}
+
+impl Error for DecodeError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::Http(e) => write!(f, "Request failed: {}", e),
+            DecodeError::Json(e) => write!(f, "Failed to parse as json: {}", e),
+            DecodeError::SchemaDrift(report) => {
+                write!(f, "Response does not match the model: {}", report)
+            }
+            DecodeError::Api(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// An error response from pixiv, e.g. for a deleted work or an expired access token.
+/// pixiv usually sends `{"error": {"user_message": ..., "message": ..., "reason": ...}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    status: u16,
+    body: Value,
+}
+
+impl ApiError {
+    /// Keep the body as JSON if it is, as a JSON string otherwise.
+    pub fn new(status: u16, body: &str) -> Self {
+        ApiError {
+            status,
+            body: serde_json::from_str(body).unwrap_or_else(|_| Value::from(body)),
+        }
+    }
+
+    /// The HTTP status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The body as sent.
+    pub fn body(&self) -> &Value {
+        &self.body
+    }
+
+    /// The first non-empty of the error's `user_message`, `message` and `reason`.
+    pub fn message(&self) -> Option<&str> {
+        let error = self.body.get("error")?;
+        ["user_message", "message", "reason"]
+            .iter()
+            .filter_map(|key| error.get(key).and_then(Value::as_str))
+            .find(|message| !message.is_empty())
+    }
+}
+
+impl Error for ApiError {}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "pixiv returned {}: {}", self.status, message),
+            None => write!(f, "pixiv returned {}: {}", self.status, self.body),
+        }
+    }
+}
+
+/// Error returned on failure to parse a search query. Positions are byte offsets into the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchQueryError {
+    /// A `"` was opened at this position and never closed.
+    UnclosedQuote { position: usize },
+    /// A `(` was opened at this position and never closed.
+    UnclosedParen { position: usize },
+    /// A `)` at this position closes nothing.
+    UnexpectedParen { position: usize },
+    /// The `OR` at this position is missing a term on one side.
+    DanglingOr { position: usize },
+    /// The `-` at this position is not followed by a term.
+    EmptyExclusion { position: usize },
+    /// The group at this position holds several terms but is an `OR` alternative.
+    /// Queries cannot say "(a b) OR c".
+    GroupInOr { position: usize },
+}
+
+impl Error for SearchQueryError {}
+
+impl fmt::Display for SearchQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SearchQueryError::UnclosedQuote { position } => {
+                write!(f, "Quote opened at {} is never closed.", position)
+            }
+            SearchQueryError::UnclosedParen { position } => {
+                write!(f, "Parenthesis opened at {} is never closed.", position)
+            }
+            SearchQueryError::UnexpectedParen { position } => {
+                write!(f, "Parenthesis at {} closes nothing.", position)
+            }
+            SearchQueryError::DanglingOr { position } => {
+                write!(f, "OR at {} is missing a term.", position)
+            }
+            SearchQueryError::EmptyExclusion { position } => {
+                write!(f, "Exclusion at {} is missing a term.", position)
+            }
+            SearchQueryError::GroupInOr { position } => write!(
+                f,
+                "Group at {} has several terms and cannot be an OR alternative.",
+                position
+            ),
+        }
+    }
+}
+
+/// Error returned on failure to write an exported novel.
+#[derive(Debug)]
+pub enum ExportError {
+    Io(std::io::Error),
+    /// The EPUB archive could not be written.
+    Zip(zip::result::ZipError),
+}
+
+impl Error for ExportError {}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportError::Io(e) => write!(f, "Failed to write the export: {}", e),
+            ExportError::Zip(e) => write!(f, "Failed to write the EPUB archive: {}", e),
+        }
+    }
+}
+
+/// Error returned on failure to download images.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// The work to download could not be fetched.
+    Decode(DecodeError),
+    /// An image request failed, or was answered with an error status.
+    Http(reqwest::Error),
+    /// An image could not be written to disk.
+    Io(std::io::Error),
+}
+
+impl Error for DownloadError {}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DownloadError::Decode(e) => write!(f, "Failed to fetch the work: {}", e),
+            DownloadError::Http(e) => write!(f, "Failed to download the image: {}", e),
+            DownloadError::Io(e) => write!(f, "Failed to save the image: {}", e),
+        }
+    }
+}
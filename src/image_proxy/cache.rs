@@ -0,0 +1,174 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+const PARTIAL_SUFFIX: &str = ".partial";
+
+/// Size-bounded on-disk cache keyed by request path.
+/// Entries are evicted least recently used first, going by their modification time.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_entry_size: u64,
+    max_size: u64,
+    counter: AtomicUsize,
+    eviction: Mutex<()>,
+}
+
+impl DiskCache {
+    /// Open (and create, if needed) a cache in `dir`.
+    pub fn open<T>(dir: T, max_entry_size: u64, max_size: u64) -> io::Result<DiskCache>
+    where
+        T: Into<PathBuf>,
+    {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(DiskCache {
+            dir,
+            max_entry_size,
+            max_size,
+            counter: AtomicUsize::new(0),
+            eviction: Mutex::new(()),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// File name an entry for `path` is stored under.
+    /// The extension is kept so the content type can be recovered on a hit.
+    fn entry_path(&self, path: &str) -> PathBuf {
+        let digest = format!("{:x}", md5::compute(path));
+        let file_name = match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => format!("{}.{}", digest, ext),
+            None => digest,
+        };
+        self.dir.join(file_name)
+    }
+
+    /// Look up the entry for `path`, marking it as recently used.
+    pub fn get(&self, path: &str) -> Option<(File, u64)> {
+        let entry = self.entry_path(path);
+        let file = File::options().read(true).write(true).open(&entry).ok()?;
+        let _ = file.set_modified(SystemTime::now());
+        let len = file.metadata().ok()?.len();
+        Some((file, len))
+    }
+
+    /// Start writing a new entry for `path`.
+    /// The entry only becomes visible once `CacheWriter::commit` is called.
+    pub fn writer(&self, path: &str) -> io::Result<CacheWriter<'_>> {
+        let target = self.entry_path(path);
+        let partial = PathBuf::from(format!(
+            "{}.{}{}",
+            target.display(),
+            self.counter.fetch_add(1, Ordering::Relaxed),
+            PARTIAL_SUFFIX
+        ));
+        Ok(CacheWriter {
+            cache: self,
+            file: Some(File::create(&partial)?),
+            partial,
+            target,
+            written: 0,
+        })
+    }
+
+    /// Total size of the committed entries.
+    pub fn size(&self) -> io::Result<u64> {
+        Ok(self.entries()?.iter().map(|(_, len, _)| len).sum())
+    }
+
+    fn entries(&self) -> io::Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.to_string_lossy().ends_with(PARTIAL_SUFFIX) {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if metadata.is_file() {
+                entries.push((path, metadata.len(), metadata.modified()?));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Remove the least recently used entries until the cache fits in its size limit.
+    fn evict(&self) -> io::Result<()> {
+        let _guard = self.eviction.lock().unwrap();
+        let mut entries = self.entries()?;
+        let mut size: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        for (path, len, _) in entries {
+            if size <= self.max_size {
+                break;
+            }
+            fs::remove_file(path)?;
+            size -= len;
+        }
+        Ok(())
+    }
+}
+
+/// An entry being written to a `DiskCache`.
+/// Writing past the cache's entry size limit silently abandons the entry.
+pub struct CacheWriter<'a> {
+    cache: &'a DiskCache,
+    file: Option<File>,
+    partial: PathBuf,
+    target: PathBuf,
+    written: u64,
+}
+
+impl<'a> CacheWriter<'a> {
+    /// Make the entry visible and evict old entries if the cache grew too large.
+    /// Returns `false` if the entry was abandoned.
+    pub fn commit(mut self) -> io::Result<bool> {
+        match self.file.take() {
+            Some(file) => {
+                file.sync_all()?;
+                fs::rename(&self.partial, &self.target)?;
+                self.cache.evict()?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn abandon(&mut self) {
+        if self.file.take().is_some() {
+            let _ = fs::remove_file(&self.partial);
+        }
+    }
+}
+
+impl<'a> Write for CacheWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written += buf.len() as u64;
+        if self.written > self.cache.max_entry_size {
+            self.abandon();
+        }
+        if let Some(file) = &mut self.file {
+            if file.write_all(buf).is_err() {
+                self.abandon();
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> Drop for CacheWriter<'a> {
+    fn drop(&mut self) {
+        self.abandon();
+    }
+}
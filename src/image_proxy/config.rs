@@ -0,0 +1,55 @@
+use crate::constants::PXIMG_HOST;
+
+use std::path::PathBuf;
+
+/// Options for `ImageProxy`.
+#[derive(Debug, Clone)]
+pub struct ImageProxyConfig {
+    pub upstream: String,
+    pub cache_dir: Option<PathBuf>,
+    pub max_entry_size: u64,
+    pub max_cache_size: u64,
+}
+
+impl Default for ImageProxyConfig {
+    fn default() -> Self {
+        ImageProxyConfig {
+            upstream: PXIMG_HOST.to_string(),
+            cache_dir: None,
+            max_entry_size: 32 * 1024 * 1024,
+            max_cache_size: 1024 * 1024 * 1024,
+        }
+    }
+}
+
+impl ImageProxyConfig {
+    /// Base URL requests are forwarded to. Defaults to `https://i.pximg.net`.
+    pub fn set_upstream<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.upstream = value.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Directory to cache responses in. Nothing is cached if this is not set.
+    pub fn set_cache_dir<T>(mut self, value: T) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        self.cache_dir = Some(value.into());
+        self
+    }
+
+    /// Responses larger than this many bytes are streamed but not cached.
+    pub fn set_max_entry_size(mut self, value: u64) -> Self {
+        self.max_entry_size = value;
+        self
+    }
+
+    /// The least recently used entries are evicted once the cache grows past this many bytes.
+    pub fn set_max_cache_size(mut self, value: u64) -> Self {
+        self.max_cache_size = value;
+        self
+    }
+}
@@ -0,0 +1,308 @@
+use crate::constants::{PIXIV_REFERER, USER_AGENT};
+use crate::image_proxy::cache::DiskCache;
+use crate::image_proxy::config::ImageProxyConfig;
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::time::Duration;
+
+const BUFFER_SIZE: usize = 16 * 1024;
+/// Longest request or header line read, in bytes.
+const MAX_LINE_LENGTH: u64 = 8 * 1024;
+const MAX_HEADERS: usize = 100;
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+/// How much of a refused request is read and dropped before closing,
+/// so the client gets the answer instead of a connection reset.
+const MAX_DRAIN_LENGTH: u64 = 64 * 1024;
+
+/// Caching HTTP proxy that forwards `GET /<path>` to `i.pximg.net`.
+///
+/// `i.pximg.net` refuses requests without a pixiv `Referer`, so its URLs cannot be embedded in a web page.
+/// The proxy adds the `Referer` and `User-Agent` pixiv expects, streams the body back,
+/// and keeps a size-bounded copy on disk if a cache directory is configured.
+/// Combine it with `PixivClient::set_image_host` to point decoded results at the proxy.
+#[derive(Debug)]
+pub struct ImageProxy {
+    upstream: String,
+    client: reqwest::Client,
+    cache: Option<DiskCache>,
+}
+
+struct ProxyRequest {
+    method: String,
+    path: String,
+}
+
+/// Why a request is refused before anything is sent upstream.
+#[derive(Debug, Clone, Copy)]
+enum Rejection {
+    BadRequest,
+    HeadersTooLarge,
+}
+
+impl ImageProxy {
+    pub fn new(config: ImageProxyConfig) -> io::Result<ImageProxy> {
+        let cache = match config.cache_dir {
+            Some(dir) => Some(DiskCache::open(
+                dir,
+                config.max_entry_size,
+                config.max_cache_size,
+            )?),
+            None => None,
+        };
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(io::Error::other)?;
+
+        Ok(ImageProxy {
+            upstream: config.upstream,
+            client,
+            cache,
+        })
+    }
+
+    pub fn cache(&self) -> Option<&DiskCache> {
+        self.cache.as_ref()
+    }
+
+    /// Bind to `addr` and serve until the listener fails.
+    pub fn bind_and_serve<A>(self, addr: A) -> io::Result<()>
+    where
+        A: ToSocketAddrs,
+    {
+        self.serve(TcpListener::bind(addr)?)
+    }
+
+    /// Serve connections from `listener`, one thread per connection.
+    pub fn serve(self, listener: TcpListener) -> io::Result<()> {
+        let proxy = Arc::new(self);
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let proxy = Arc::clone(&proxy);
+            std::thread::spawn(move || {
+                if let Err(e) = proxy.handle(stream) {
+                    warn!("image proxy: failed to handle connection: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn handle(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut stream = stream;
+
+        let request = match read_request(&mut reader)? {
+            Ok(request) => request,
+            Err(rejection) => return reject(&mut stream, reader, rejection),
+        };
+
+        if request.method != "GET" && request.method != "HEAD" {
+            return write_status(&mut stream, 405, "Method Not Allowed");
+        }
+        if !is_safe_path(&request.path) {
+            return write_status(&mut stream, 400, "Bad Request");
+        }
+        let head_only = request.method == "HEAD";
+
+        if let Some((file, len)) = self.cache.as_ref().and_then(|c| c.get(&request.path)) {
+            write_head(
+                &mut stream,
+                200,
+                "OK",
+                content_type(&request.path),
+                Some(len),
+            )?;
+            if !head_only {
+                io::copy(&mut BufReader::new(file), &mut stream)?;
+            }
+            return stream.flush();
+        }
+
+        let url = format!("{}{}", self.upstream, request.path);
+        let mut response = match self
+            .client
+            .get(url.as_str())
+            .header(reqwest::header::REFERER, PIXIV_REFERER)
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .send()
+        {
+            Ok(response) => response,
+            Err(e) => {
+                warn!("image proxy: upstream request to {} failed: {}", url, e);
+                return write_status(&mut stream, 502, "Bad Gateway");
+            }
+        };
+
+        let status = response.status();
+        let upstream_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let len = response
+            .headers()
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+
+        write_head(
+            &mut stream,
+            status.as_u16(),
+            status.canonical_reason().unwrap_or(""),
+            upstream_type
+                .as_deref()
+                .unwrap_or_else(|| content_type(&request.path)),
+            len,
+        )?;
+        if head_only {
+            return stream.flush();
+        }
+
+        let mut writer = match &self.cache {
+            Some(cache) if status.is_success() => cache.writer(&request.path).ok(),
+            _ => None,
+        };
+
+        // The last chunk is held back until the cache entry is committed,
+        // so a client holding the whole body can count on the entry being there.
+        let mut buffer = [0; BUFFER_SIZE];
+        let mut pending = Vec::with_capacity(BUFFER_SIZE);
+        loop {
+            let read = response.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            if let Some(writer) = &mut writer {
+                writer.write_all(&buffer[..read])?;
+            }
+            stream.write_all(&pending)?;
+            pending.clear();
+            pending.extend_from_slice(&buffer[..read]);
+        }
+
+        if let Some(writer) = writer {
+            writer.commit()?;
+        }
+        stream.write_all(&pending)?;
+        stream.flush()
+    }
+}
+
+fn read_request<R>(reader: &mut R) -> io::Result<Result<ProxyRequest, Rejection>>
+where
+    R: BufRead,
+{
+    let mut line = String::new();
+    if !read_line(reader, &mut line)? {
+        return Ok(Err(Rejection::BadRequest));
+    }
+    let mut parts = line.split_whitespace();
+    let request = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => ProxyRequest {
+            method: method.to_string(),
+            path: target.split('?').next().unwrap_or_default().to_string(),
+        },
+        _ => return Ok(Err(Rejection::BadRequest)),
+    };
+
+    // Drain the headers, none of them are forwarded.
+    let mut headers = 0;
+    loop {
+        if !read_line(reader, &mut line)? {
+            return Ok(Err(Rejection::HeadersTooLarge));
+        }
+        if line.trim().is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Ok(Err(Rejection::HeadersTooLarge));
+        }
+    }
+
+    Ok(Ok(request))
+}
+
+/// Read a line of at most `MAX_LINE_LENGTH` bytes into `line`, replacing its contents.
+/// Returns `false` if the line is longer; the end of the stream counts as an empty line.
+fn read_line<R>(reader: &mut R, line: &mut String) -> io::Result<bool>
+where
+    R: BufRead,
+{
+    line.clear();
+    let read = reader.by_ref().take(MAX_LINE_LENGTH).read_line(line)?;
+    Ok((read as u64) < MAX_LINE_LENGTH || line.ends_with('\n'))
+}
+
+/// Answer a refused request, then drop what is left of it before closing.
+fn reject<R>(stream: &mut TcpStream, mut reader: R, rejection: Rejection) -> io::Result<()>
+where
+    R: Read,
+{
+    match rejection {
+        Rejection::BadRequest => write_status(stream, 400, "Bad Request")?,
+        Rejection::HeadersTooLarge => write_status(stream, 431, "Request Header Fields Too Large")?,
+    }
+    stream.shutdown(Shutdown::Write)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    // The client may be gone or still sending, either way the answer is out.
+    let _ = io::copy(&mut reader.by_ref().take(MAX_DRAIN_LENGTH), &mut io::sink());
+    Ok(())
+}
+
+/// Only plain absolute paths are forwarded; anything that could climb out of the upstream root is refused.
+fn is_safe_path(path: &str) -> bool {
+    path.starts_with('/')
+        && path.len() > 1
+        && !path.contains('\\')
+        && !path
+            .split('/')
+            .any(|segment| segment.to_lowercase().replace("%2e", ".") == "..")
+}
+
+fn content_type(path: &str) -> &'static str {
+    match path.rsplit('.').next() {
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("zip") => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+fn write_head<W>(
+    stream: &mut W,
+    status: u16,
+    reason: &str,
+    content_type: &str,
+    len: Option<u64>,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    write!(stream, "HTTP/1.1 {} {}\r\n", status, reason)?;
+    write!(stream, "Content-Type: {}\r\n", content_type)?;
+    if let Some(len) = len {
+        write!(stream, "Content-Length: {}\r\n", len)?;
+    }
+    if status == 200 {
+        write!(stream, "Cache-Control: public, max-age=31536000\r\n")?;
+    }
+    write!(stream, "Connection: close\r\n\r\n")
+}
+
+fn write_status<W>(stream: &mut W, status: u16, reason: &str) -> io::Result<()>
+where
+    W: Write,
+{
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status, reason
+    )?;
+    stream.flush()
+}
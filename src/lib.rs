@@ -2,15 +2,19 @@ extern crate bytes;
 extern crate chrono;
 extern crate dotenv;
 extern crate http;
+#[macro_use]
+extern crate log;
 extern crate md5;
 extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
 extern crate serde_urlencoded;
 extern crate url;
+extern crate zip;
 
 pub mod constants;
 pub mod enums;
 pub mod errors;
+pub mod image_proxy;
 pub mod pixiv;
 pub mod utils;
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// Arguments for posting a comment on an illustration, as text or as a stamp.
+#[derive(Serialize, Deserialize)]
+pub struct IllustrationCommentAddRequestArg {
+    illust_id: usize,
+    comment: Option<String>,
+    stamp_id: Option<u32>,
+    parent_comment_id: Option<usize>,
+}
+
+impl IllustrationCommentAddRequestArg {
+    /// A text comment.
+    pub fn new<T>(illust_id: usize, comment: T) -> Self
+    where
+        T: Into<String>,
+    {
+        IllustrationCommentAddRequestArg {
+            illust_id,
+            comment: Some(comment.into()),
+            stamp_id: None,
+            parent_comment_id: None,
+        }
+    }
+
+    /// A stamp, see `Stamp::stamp_id`.
+    pub fn stamp(illust_id: usize, stamp_id: u32) -> Self {
+        IllustrationCommentAddRequestArg {
+            illust_id,
+            comment: None,
+            stamp_id: Some(stamp_id),
+            parent_comment_id: None,
+        }
+    }
+
+    /// Post the comment as a reply.
+    pub fn set_parent_comment_id(mut self, parent_comment_id: usize) -> Self {
+        self.parent_comment_id = Some(parent_comment_id);
+        self
+    }
+
+    pub fn build(self) -> std::collections::HashMap<&'static str, String> {
+        let mut result = std::collections::HashMap::new();
+
+        result.insert("illust_id", self.illust_id.to_string());
+
+        if let Some(comment) = self.comment {
+            result.insert("comment", comment);
+        }
+
+        if let Some(stamp_id) = self.stamp_id {
+            result.insert("stamp_id", stamp_id.to_string());
+        }
+
+        if let Some(parent_comment_id) = self.parent_comment_id {
+            result.insert("parent_comment_id", parent_comment_id.to_string());
+        }
+
+        result
+    }
+}
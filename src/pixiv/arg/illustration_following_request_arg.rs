@@ -0,0 +1,50 @@
+use crate::enums::Visibility;
+
+use serde::{Deserialize, Serialize};
+
+/// Arguments for listing the newest works of the users the logged in user follows.
+#[derive(Serialize, Deserialize)]
+pub struct IllustrationFollowingRequestArg {
+    restrict: Visibility,
+    offset: u32,
+}
+
+impl IllustrationFollowingRequestArg {
+    /// `Public` or `Private` follows.
+    pub fn new<T>(visibility: T) -> Self
+    where
+        T: Into<Visibility>,
+    {
+        IllustrationFollowingRequestArg {
+            restrict: visibility.into(),
+            offset: 0,
+        }
+    }
+
+    pub fn set_offset(mut self, value: u32) -> Self {
+        self.offset = value;
+        self
+    }
+
+    pub fn build(self) -> std::collections::HashMap<&'static str, String> {
+        let mut result = std::collections::HashMap::new();
+
+        result.insert("restrict", self.restrict.as_str().to_string());
+        result.insert("offset", self.offset.to_string());
+
+        result
+    }
+}
+
+/// List public follows if `true`, private ones if `false`, from the start.
+impl From<bool> for IllustrationFollowingRequestArg {
+    fn from(public: bool) -> Self {
+        IllustrationFollowingRequestArg::new(public)
+    }
+}
+
+impl From<Visibility> for IllustrationFollowingRequestArg {
+    fn from(visibility: Visibility) -> Self {
+        IllustrationFollowingRequestArg::new(visibility)
+    }
+}
@@ -1,13 +1,15 @@
+use crate::enums::pixiv_today;
 use crate::enums::Filter;
 use crate::enums::RankingMode;
+use crate::errors::RankingDateError;
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
 pub struct IllustrationRankingRequestArg {
     mode: RankingMode,
-    // TODO: Figure out how to encapsulate the date properly.
-    date: Option<String>,
+    date: Option<NaiveDate>,
     offset: u32,
     filter: Filter,
 }
@@ -32,12 +34,12 @@ impl IllustrationRankingRequestArg {
         self
     }
 
-    pub fn set_date<T>(mut self, value: T) -> Self
-    where
-        T: Into<String>,
-    {
-        self.date = Some(value.into());
-        self
+    /// Ask for the ranking of a past day.
+    /// The date is checked against the mode set so far, and again against the final mode by `build`.
+    pub fn set_date(mut self, value: NaiveDate) -> Result<Self, RankingDateError> {
+        self.mode.validate_date(value, pixiv_today())?;
+        self.date = Some(value);
+        Ok(self)
     }
 
     pub fn set_offset(mut self, value: u32) -> Self {
@@ -53,18 +55,24 @@ impl IllustrationRankingRequestArg {
         self
     }
 
-    pub fn build(self) -> std::collections::HashMap<&'static str, String> {
+    /// Fails if pixiv has no ranking of the mode for the date set.
+    pub fn build(
+        self,
+    ) -> Result<std::collections::HashMap<&'static str, String>, RankingDateError> {
+        if let Some(date) = self.date {
+            self.mode.validate_date(date, pixiv_today())?;
+        }
         let mut result = std::collections::HashMap::new();
 
         result.insert("mode", self.mode.as_str().to_string());
 
         if let Some(date) = self.date {
-            result.insert("date", date);
+            result.insert("date", date.format("%Y-%m-%d").to_string());
         }
 
         result.insert("offset", self.offset.to_string());
         result.insert("filter", self.filter.as_str().to_string());
 
-        result
+        Ok(result)
     }
 }
@@ -1,4 +1,6 @@
-use crate::enums::{Duration, SearchSort, SearchTarget};
+use crate::enums::{Duration, Filter, SearchAiType, SearchSort, SearchTarget};
+
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -7,8 +9,15 @@ pub struct IllustrationSearchRequestArg {
     search_target: SearchTarget,
     sort: SearchSort,
     duration: Option<Duration>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    min_bookmarks: Option<u32>,
+    max_bookmarks: Option<u32>,
+    search_ai_type: Option<SearchAiType>,
+    merge_plain_keyword_results: Option<bool>,
+    include_translated_tag_results: Option<bool>,
     offset: Option<u32>,
-    filter: Option<&'static str>,
+    filter: Option<Filter>,
 }
 
 impl IllustrationSearchRequestArg {
@@ -21,6 +30,13 @@ impl IllustrationSearchRequestArg {
             search_target: SearchTarget::TagsPartial,
             sort: SearchSort::DateDescending,
             duration: None,
+            start_date: None,
+            end_date: None,
+            min_bookmarks: None,
+            max_bookmarks: None,
+            search_ai_type: None,
+            merge_plain_keyword_results: None,
+            include_translated_tag_results: None,
             offset: None,
             filter: None,
         }
@@ -50,10 +66,62 @@ impl IllustrationSearchRequestArg {
         self
     }
 
+    /// Only works posted on or after this day.
+    pub fn set_start_date(mut self, start_date: NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Only works posted on or before this day.
+    pub fn set_end_date(mut self, end_date: NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Only works with at least this many bookmarks.
+    pub fn set_min_bookmarks(mut self, min_bookmarks: u32) -> Self {
+        self.min_bookmarks = Some(min_bookmarks);
+        self
+    }
+
+    /// Only works with at most this many bookmarks.
+    pub fn set_max_bookmarks(mut self, max_bookmarks: u32) -> Self {
+        self.max_bookmarks = Some(max_bookmarks);
+        self
+    }
+
+    pub fn set_search_ai_type<T>(mut self, search_ai_type: T) -> Self
+    where
+        T: Into<SearchAiType>,
+    {
+        self.search_ai_type = Some(search_ai_type.into());
+        self
+    }
+
+    /// Also match the words outside of tags, like the website does.
+    pub fn set_merge_plain_keyword_results(mut self, value: bool) -> Self {
+        self.merge_plain_keyword_results = Some(value);
+        self
+    }
+
+    /// Also match the translated names of tags.
+    pub fn set_include_translated_tag_results(mut self, value: bool) -> Self {
+        self.include_translated_tag_results = Some(value);
+        self
+    }
+
     pub fn set_offset(mut self, offset: u32) -> Self {
         self.offset = Some(offset.into());
         self
     }
+
+    pub fn set_filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Filter>,
+    {
+        self.filter = Some(filter.into());
+        self
+    }
 }
 
 impl IntoIterator for IllustrationSearchRequestArg {
@@ -88,8 +156,47 @@ impl Iterator for IllustrationSearchParamIterator {
                     .duration
                     .take()
                     .map(|x| ("duration", x.as_str().to_string())),
-                4 => self.vec.offset.take().map(|x| ("offset", x.to_string())),
-                5 => self.vec.filter.take().map(|x| ("filter", x.to_string())),
+                4 => self
+                    .vec
+                    .start_date
+                    .take()
+                    .map(|x| ("start_date", x.format("%Y-%m-%d").to_string())),
+                5 => self
+                    .vec
+                    .end_date
+                    .take()
+                    .map(|x| ("end_date", x.format("%Y-%m-%d").to_string())),
+                6 => self
+                    .vec
+                    .min_bookmarks
+                    .take()
+                    .map(|x| ("bookmark_num_min", x.to_string())),
+                7 => self
+                    .vec
+                    .max_bookmarks
+                    .take()
+                    .map(|x| ("bookmark_num_max", x.to_string())),
+                8 => self
+                    .vec
+                    .search_ai_type
+                    .take()
+                    .map(|x| ("search_ai_type", x.value().to_string())),
+                9 => self
+                    .vec
+                    .merge_plain_keyword_results
+                    .take()
+                    .map(|x| ("merge_plain_keyword_results", x.to_string())),
+                10 => self
+                    .vec
+                    .include_translated_tag_results
+                    .take()
+                    .map(|x| ("include_translated_tag_results", x.to_string())),
+                11 => self.vec.offset.take().map(|x| ("offset", x.to_string())),
+                12 => self
+                    .vec
+                    .filter
+                    .take()
+                    .map(|x| ("filter", x.as_str().to_string())),
                 _ => return None,
             };
             self.index += 1;
@@ -1,5 +1,11 @@
+pub mod illustration_comment_add_request_arg;
+pub mod illustration_following_request_arg;
 pub mod illustration_ranking_request_arg;
 pub mod illustration_search_request_arg;
+pub mod new_illustrations_request_arg;
 pub mod recommended_illustration_request_arg;
 pub mod user_bookmark_tags_illustration_request_arg;
+pub mod user_bookmarks_illustration_request_arg;
 pub mod user_following_request_arg;
+pub mod user_illustrations_request_arg;
+pub mod user_search_request_arg;
@@ -0,0 +1,65 @@
+use crate::enums::ContentType;
+use crate::enums::Filter;
+
+use serde::{Deserialize, Serialize};
+
+/// Arguments for listing the newest works on pixiv. Lists illustrations from the newest unless told otherwise.
+#[derive(Serialize, Deserialize)]
+pub struct NewIllustrationsRequestArg {
+    content_type: ContentType,
+    max_illust_id: Option<u32>,
+    filter: Filter,
+}
+
+impl Default for NewIllustrationsRequestArg {
+    fn default() -> Self {
+        NewIllustrationsRequestArg {
+            content_type: ContentType::Illustration,
+            max_illust_id: None,
+            filter: Filter::ForiOS,
+        }
+    }
+}
+
+impl NewIllustrationsRequestArg {
+    pub fn new() -> Self {
+        NewIllustrationsRequestArg::default()
+    }
+
+    /// `Illustration` or `Manga`.
+    pub fn set_content_type<T>(mut self, value: T) -> Self
+    where
+        T: Into<ContentType>,
+    {
+        self.content_type = value.into();
+        self
+    }
+
+    /// Only list works up to this id, to continue from an earlier listing.
+    pub fn set_max_illust_id(mut self, value: u32) -> Self {
+        self.max_illust_id = Some(value);
+        self
+    }
+
+    pub fn set_filter<T>(mut self, value: T) -> Self
+    where
+        T: Into<Filter>,
+    {
+        self.filter = value.into();
+        self
+    }
+
+    pub fn build(self) -> std::collections::HashMap<&'static str, String> {
+        let mut result = std::collections::HashMap::new();
+
+        result.insert("content_type", self.content_type.as_str().to_string());
+
+        if let Some(max_illust_id) = self.max_illust_id {
+            result.insert("max_illust_id", max_illust_id.to_string());
+        }
+
+        result.insert("filter", self.filter.as_str().to_string());
+
+        result
+    }
+}
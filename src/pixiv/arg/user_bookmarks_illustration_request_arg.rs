@@ -0,0 +1,77 @@
+use crate::enums::Filter;
+use crate::enums::Visibility;
+
+use serde::{Deserialize, Serialize};
+
+/// Arguments for listing the illustrations or novels a user bookmarked, newest first.
+/// Private bookmarks can only be listed for the logged in user.
+#[derive(Serialize, Deserialize)]
+pub struct UserBookmarksIllustrationRequestArg {
+    user_id: u32,
+    restrict: Visibility,
+    tag: Option<String>,
+    max_bookmark_id: Option<u64>,
+    filter: Filter,
+}
+
+impl UserBookmarksIllustrationRequestArg {
+    pub fn new(user_id: u32) -> Self {
+        UserBookmarksIllustrationRequestArg {
+            user_id,
+            restrict: Visibility::Public,
+            tag: None,
+            max_bookmark_id: None,
+            filter: Filter::ForiOS,
+        }
+    }
+
+    pub fn set_restrict<T>(mut self, value: T) -> Self
+    where
+        T: Into<Visibility>,
+    {
+        self.restrict = value.into();
+        self
+    }
+
+    /// Only list bookmarks registered with this bookmark tag.
+    pub fn set_tag<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.tag = Some(value.into());
+        self
+    }
+
+    /// Start from the bookmarks older than this one. pixiv pages this endpoint by id, not offset.
+    pub fn set_max_bookmark_id(mut self, value: u64) -> Self {
+        self.max_bookmark_id = Some(value);
+        self
+    }
+
+    pub fn set_filter<T>(mut self, value: T) -> Self
+    where
+        T: Into<Filter>,
+    {
+        self.filter = value.into();
+        self
+    }
+
+    pub fn build(self) -> std::collections::HashMap<&'static str, String> {
+        let mut result = std::collections::HashMap::new();
+
+        result.insert("user_id", self.user_id.to_string());
+        result.insert("restrict", self.restrict.as_str().to_string());
+
+        if let Some(tag) = self.tag {
+            result.insert("tag", tag);
+        }
+
+        if let Some(max_bookmark_id) = self.max_bookmark_id {
+            result.insert("max_bookmark_id", max_bookmark_id.to_string());
+        }
+
+        result.insert("filter", self.filter.as_str().to_string());
+
+        result
+    }
+}
@@ -0,0 +1,75 @@
+use crate::enums::{Duration, Filter, SearchSort};
+use serde::{Deserialize, Serialize};
+
+/// Arguments for finding users by name or account.
+#[derive(Serialize, Deserialize)]
+pub struct UserSearchRequestArg {
+    word: String,
+    sort: SearchSort,
+    duration: Option<Duration>,
+    offset: Option<u32>,
+    filter: Filter,
+}
+
+impl UserSearchRequestArg {
+    pub fn new<T>(word: T) -> Self
+    where
+        T: Into<String>,
+    {
+        UserSearchRequestArg {
+            word: word.into(),
+            sort: SearchSort::DateDescending,
+            duration: None,
+            offset: None,
+            filter: Filter::ForiOS,
+        }
+    }
+
+    pub fn set_sort<T>(mut self, sort: T) -> Self
+    where
+        T: Into<SearchSort>,
+    {
+        self.sort = sort.into();
+        self
+    }
+
+    pub fn set_duration<T>(mut self, duration: T) -> Self
+    where
+        T: Into<Duration>,
+    {
+        self.duration = Some(duration.into());
+        self
+    }
+
+    pub fn set_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn set_filter<T>(mut self, value: T) -> Self
+    where
+        T: Into<Filter>,
+    {
+        self.filter = value.into();
+        self
+    }
+
+    pub fn build(self) -> std::collections::HashMap<&'static str, String> {
+        let mut result = std::collections::HashMap::new();
+
+        result.insert("word", self.word);
+        result.insert("sort", self.sort.as_str().to_string());
+
+        if let Some(duration) = self.duration {
+            result.insert("duration", duration.as_str().to_string());
+        }
+
+        if let Some(offset) = self.offset {
+            result.insert("offset", offset.to_string());
+        }
+
+        result.insert("filter", self.filter.as_str().to_string());
+
+        result
+    }
+}
@@ -1,21 +1,46 @@
 use crate::constants::{
-    AUTH_URL, CLIENT_ID, CLIENT_SECRET, HASH_SECRET, USER_AGENT, X_CLIENT_HASH, X_CLIENT_TIME,
+    AUTH_URL, CLIENT_ID, CLIENT_SECRET, HASH_SECRET, PIXIV_REFERER, USER_AGENT, X_CLIENT_HASH,
+    X_CLIENT_TIME,
 };
-use crate::errors::AuthError;
+use crate::enums::{pixiv_today, RankingMode};
+use crate::errors::{ApiError, AuthError, DecodeError, DownloadError};
 use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::helper_structs::pximg_url::PximgUrl;
+use crate::pixiv::helper_structs::spotlight_article::SpotlightArticle;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::novel_export::NovelBook;
+use crate::pixiv::pagination::{Pages, Paginated};
+use crate::pixiv::ranking::RankingCrawl;
 use crate::pixiv::request::PixivRequest;
-
+use crate::pixiv::request_builder::PixivRequestBuilder;
+use crate::pixiv::result::illustration_ranking::IllustrationRanking;
+use crate::pixiv::result::illustration_series::IllustrationSeries;
+use crate::pixiv::result::novel_ranking::NovelRanking;
+use crate::pixiv::result::novel_series::NovelSeries;
+use crate::pixiv::result::novel_webview::NovelWebview;
+use crate::pixiv::schema::{self, SchemaMode};
+use crate::pixiv::spotlight;
+
+use chrono::NaiveDate;
 use http::{header, status::StatusCode};
 use md5;
 use reqwest::{Client, ClientBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
 
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
 /// Used to authenticate to the PixivClient servers and construct PixivClient requests through methods creating `PixivRequestBuilder`.
 #[derive(Debug, Clone)]
 pub struct PixivClient {
     pub client: Client,
     pub access_token: String,
     pub refresh_token: String,
+    pub image_host: Option<String>,
+    pub schema_mode: SchemaMode,
 }
 
 impl PixivClient {
@@ -34,6 +59,8 @@ impl PixivClient {
             client: client,
             access_token: String::default(),
             refresh_token: String::default(),
+            image_host: None,
+            schema_mode: SchemaMode::default(),
         })
     }
     /// This is required to use all the other functions this library provides. Requires a valid username and password.
@@ -146,6 +173,48 @@ impl PixivClient {
         &mut self.refresh_token
     }
 
+    /// Serve images from `host` (e.g. a reverse proxy or a public mirror) instead of `i.pximg.net`.
+    /// Every result the client decodes has its image URLs rewritten, so images the client downloads
+    /// come from `host` too.
+    pub fn set_image_host<T>(&mut self, host: T)
+    where
+        T: Into<String>,
+    {
+        self.image_host = Some(host.into());
+    }
+
+    /// Go back to serving images from `i.pximg.net`.
+    pub fn clear_image_host(&mut self) {
+        self.image_host = None;
+    }
+
+    /// Get the image host, if one is set.
+    pub fn image_host(&self) -> Option<&str> {
+        self.image_host.as_deref()
+    }
+
+    /// Point every image URL in a result decoded elsewhere at the configured image host.
+    /// This is a no-op if no image host is set, or if the result came from `decode`.
+    pub fn apply_image_host<T>(&self, mut value: T) -> T
+    where
+        T: RewriteImageHost,
+    {
+        if let Some(host) = &self.image_host {
+            value.rewrite_image_host(host);
+        }
+        value
+    }
+
+    /// Choose how `decode` reacts to responses that drift from the models.
+    pub fn set_schema_mode(&mut self, mode: SchemaMode) {
+        self.schema_mode = mode;
+    }
+
+    /// Get the schema mode.
+    pub fn schema_mode(&self) -> SchemaMode {
+        self.schema_mode
+    }
+
     /// Get current UTC time as a `String`.
     fn get_current_time(&self) -> String {
         chrono::offset::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
@@ -191,6 +260,81 @@ impl PixivClient {
             .send()
     }
 
+    /// Decode a response into a model, checking it for schema drift according to `schema_mode`.
+    /// Image URLs point at the image host, if one is set.
+    /// Error statuses are returned as `DecodeError::Api` with the body pixiv sent.
+    pub fn decode<T>(&self, response: &mut Response) -> Result<T, DecodeError>
+    where
+        T: DeserializeOwned + Serialize + RewriteImageHost,
+    {
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().map_err(DecodeError::Http)?;
+            return Err(DecodeError::Api(ApiError::new(status.as_u16(), &body)));
+        }
+        let value = response.json().map_err(DecodeError::Http)?;
+        self.decode_value(value)
+    }
+
+    /// `decode` for a response body that was already read.
+    pub fn decode_value<T>(&self, value: Value) -> Result<T, DecodeError>
+    where
+        T: DeserializeOwned + Serialize + RewriteImageHost,
+    {
+        schema::decode(value, self.schema_mode).map(|model| self.apply_image_host(model))
+    }
+
+    /// Executes a given `PixivRequest` and decodes the response, see `decode`.
+    pub fn execute_and_decode<T>(&self, request: PixivRequest) -> Result<T, DecodeError>
+    where
+        T: DeserializeOwned + Serialize + RewriteImageHost,
+    {
+        let mut response = self.execute_with_auth(request).map_err(DecodeError::Http)?;
+        self.decode(&mut response)
+    }
+
+    /// Iterate over every page of a paginated endpoint, starting with `first`.
+    pub fn pages<T>(&self, first: PixivRequest) -> Pages<'_, T>
+    where
+        T: Paginated + DeserializeOwned + Serialize + RewriteImageHost,
+    {
+        Pages::new(self, first)
+    }
+
+    /// Walk the illustration ranking of `mode` for every day from `start` to `end`, both included,
+    /// yielding `(date, rank, illustration)`. Days before the mode existed or from today on in Japan
+    /// are skipped, see `RankingMode::dates_between`.
+    pub fn crawl_illustration_ranking(
+        &self,
+        mode: RankingMode,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> RankingCrawl<&PixivClient, IllustrationRanking> {
+        let dates = mode.dates_between(start, end, pixiv_today());
+        RankingCrawl::new(
+            self,
+            mode,
+            dates,
+            PixivRequestBuilder::request_illustrations_ranking,
+        )
+    }
+
+    /// `crawl_illustration_ranking` for the novel ranking, yielding `(date, rank, novel)`.
+    pub fn crawl_novel_ranking(
+        &self,
+        mode: RankingMode,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> RankingCrawl<&PixivClient, NovelRanking> {
+        let dates = mode.dates_between(start, end, pixiv_today());
+        RankingCrawl::new(
+            self,
+            mode,
+            dates,
+            PixivRequestBuilder::request_novels_ranking,
+        )
+    }
+
     /// Download a given illustration to path
     pub fn download_illustration<'a, 'b, 'c>(
         &'a self,
@@ -199,4 +343,129 @@ impl PixivClient {
     ) {
         illustration.download(&self.client, &path);
     }
+
+    /// Fetch an illustration or manga series with every page of its works.
+    /// Use `IllustrationSeries::reading_order` to walk them from the first.
+    pub fn fetch_illustration_series(
+        &self,
+        series_id: usize,
+    ) -> Result<IllustrationSeries, DecodeError> {
+        let mut pages = self.pages::<IllustrationSeries>(
+            PixivRequestBuilder::request_illustration_series(series_id),
+        );
+        let mut series = pages.next().expect("The first page is always fetched")?;
+        for page in pages {
+            series.append(page?);
+        }
+        Ok(series)
+    }
+
+    /// Download the original of every page of every work of a series into the directory `path`,
+    /// oldest first, see `download_originals`.
+    pub fn download_illustration_series(
+        &self,
+        series_id: usize,
+        path: &Path,
+    ) -> Result<IllustrationSeries, DownloadError> {
+        let series = self
+            .fetch_illustration_series(series_id)
+            .map_err(DownloadError::Decode)?;
+        for illustration in series.reading_order() {
+            self.download_originals(illustration, path)?;
+        }
+        Ok(series)
+    }
+
+    /// Download the original upload of every page of an illustration into the directory `path`,
+    /// returning the files written in page order. Stops at the first page that fails.
+    pub fn download_originals(
+        &self,
+        illustration: &Illustration,
+        path: &Path,
+    ) -> Result<Vec<PathBuf>, DownloadError> {
+        illustration
+            .original_urls()
+            .into_iter()
+            .map(|url| self.download_image(url, path))
+            .collect()
+    }
+
+    /// Download one image into the directory `path`, named after the last segment of its URL.
+    pub fn download_image(&self, url: &str, path: &Path) -> Result<PathBuf, DownloadError> {
+        let mut response = self
+            .client
+            .get(url)
+            .header(header::REFERER, PIXIV_REFERER)
+            .send()
+            .and_then(Response::error_for_status)
+            .map_err(DownloadError::Http)?;
+        let name = response
+            .url()
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|name| !name.is_empty())
+            .unwrap_or("image.bin")
+            .to_string();
+        let destination = path.join(name);
+        let mut file = File::create(&destination).map_err(DownloadError::Io)?;
+        io::copy(&mut response, &mut file).map_err(DownloadError::Io)?;
+        Ok(destination)
+    }
+
+    /// Fetch a novel series with every page of its novels, in the order the author arranged them.
+    pub fn fetch_novel_series(&self, series_id: usize) -> Result<NovelSeries, DecodeError> {
+        let mut pages =
+            self.pages::<NovelSeries>(PixivRequestBuilder::request_novel_series(series_id));
+        let mut series = pages.next().expect("The first page is always fetched")?;
+        for page in pages {
+            series.append(page?);
+        }
+        Ok(series)
+    }
+
+    /// Fetch the body of a novel from the novel viewer page, see `request_novel_webview`.
+    pub fn fetch_novel_text(&self, novel_id: usize) -> Result<NovelWebview, DecodeError> {
+        let mut response = self
+            .execute_with_auth(PixivRequestBuilder::request_novel_webview(novel_id))
+            .map_err(DecodeError::Http)?;
+        let status = response.status();
+        let body = response.text().map_err(DecodeError::Http)?;
+        if !status.is_success() {
+            return Err(DecodeError::Api(ApiError::new(status.as_u16(), &body)));
+        }
+        self.decode_value(NovelWebview::payload(&body)?)
+    }
+
+    /// Fetch a novel series with the text of every novel, as a book ready to export.
+    pub fn fetch_novel_series_book(&self, series_id: usize) -> Result<NovelBook, DecodeError> {
+        let series = self.fetch_novel_series(series_id)?;
+        let mut book = NovelBook::new(series.detail().title())
+            .set_author(series.detail().user().name())
+            .set_identifier(series.detail().web_url());
+        for novel in series.novels() {
+            let text = self.fetch_novel_text(novel.id() as usize)?;
+            book = book.add_novel(novel, text.document());
+        }
+        Ok(book)
+    }
+
+    /// Fetch a Pixivision article and read the ids of the illustrations it features,
+    /// see `spotlight::featured_illust_ids`.
+    pub fn fetch_spotlight_illust_ids(
+        &self,
+        article: &SpotlightArticle,
+    ) -> Result<Vec<u32>, reqwest::Error> {
+        let html = self
+            .client
+            .get(article.article_url().as_str())
+            .send()?
+            .error_for_status()?
+            .text()?;
+        Ok(spotlight::featured_illust_ids(&html))
+    }
+
+    /// Find the original upload of a page whose extension is unknown, see `PximgUrl::probe_original`.
+    pub fn probe_original_url(&self, url: &PximgUrl) -> Option<String> {
+        url.probe_original(&self.client)
+    }
 }
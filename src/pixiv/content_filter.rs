@@ -0,0 +1,183 @@
+use crate::enums::{AiType, SanityLevel, XRestrict};
+use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::helper_structs::tag::Tag;
+use crate::pixiv::pagination::Paginated;
+
+use std::collections::HashSet;
+
+/// A work a `ContentFilter` can judge.
+pub trait FilterableWork {
+    fn ai_type(&self) -> AiType;
+
+    fn x_restrict(&self) -> XRestrict;
+
+    /// `None` for works pixiv does not rate, which pass any maximum sanity level.
+    fn sanity_level(&self) -> Option<SanityLevel>;
+
+    fn user_id(&self) -> u32;
+
+    fn tags(&self) -> &[Tag];
+}
+
+impl FilterableWork for Illustration {
+    fn ai_type(&self) -> AiType {
+        self.illust_ai_type()
+    }
+
+    fn x_restrict(&self) -> XRestrict {
+        self.x_restrict()
+    }
+
+    fn sanity_level(&self) -> Option<SanityLevel> {
+        Some(self.sanity_level())
+    }
+
+    fn user_id(&self) -> u32 {
+        self.user().id()
+    }
+
+    fn tags(&self) -> &[Tag] {
+        self.tags()
+    }
+}
+
+/// Novels are not rated, only restricted.
+impl FilterableWork for Novel {
+    fn ai_type(&self) -> AiType {
+        self.novel_ai_type()
+    }
+
+    fn x_restrict(&self) -> XRestrict {
+        self.x_restrict()
+    }
+
+    fn sanity_level(&self) -> Option<SanityLevel> {
+        None
+    }
+
+    fn user_id(&self) -> u32 {
+        self.user().id()
+    }
+
+    fn tags(&self) -> &[Tag] {
+        self.tags()
+    }
+}
+
+/// Client-side filter for illustrations and novels pixiv returns regardless of the account settings.
+/// The default hides AI-generated, R-18 and R-18G works.
+#[derive(Debug, Clone, Default)]
+pub struct ContentFilter {
+    max_sanity_level: Option<SanityLevel>,
+    allow_ai: bool,
+    allow_r18: bool,
+    allow_r18g: bool,
+    muted_user_ids: HashSet<u32>,
+    muted_tags: HashSet<String>,
+}
+
+/// How many items a `ContentFilter` kept and dropped from a page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FilterReport {
+    pub kept: usize,
+    pub dropped: usize,
+}
+
+impl ContentFilter {
+    /// A filter that lets everything through, to build an allow-list style filter from.
+    pub fn allow_all() -> Self {
+        ContentFilter {
+            allow_ai: true,
+            allow_r18: true,
+            allow_r18g: true,
+            ..ContentFilter::default()
+        }
+    }
+
+    /// Drop works rated more sensitive than `level`.
+    pub fn set_max_sanity_level(mut self, level: SanityLevel) -> Self {
+        self.max_sanity_level = Some(level);
+        self
+    }
+
+    pub fn set_allow_ai(mut self, allow: bool) -> Self {
+        self.allow_ai = allow;
+        self
+    }
+
+    pub fn set_allow_r18(mut self, allow: bool) -> Self {
+        self.allow_r18 = allow;
+        self
+    }
+
+    pub fn set_allow_r18g(mut self, allow: bool) -> Self {
+        self.allow_r18g = allow;
+        self
+    }
+
+    pub fn mute_user(mut self, user_id: u32) -> Self {
+        self.muted_user_ids.insert(user_id);
+        self
+    }
+
+    /// Tags are matched against the tag name exactly, not the translation.
+    pub fn mute_tag<S>(mut self, tag: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.muted_tags.insert(tag.into());
+        self
+    }
+
+    /// Whether `work` passes the filter.
+    pub fn allows<W>(&self, work: &W) -> bool
+    where
+        W: FilterableWork,
+    {
+        if let (Some(max), Some(level)) = (self.max_sanity_level, work.sanity_level()) {
+            if level > max {
+                return false;
+            }
+        }
+        self.allows_ai_type(work.ai_type())
+            && self.allows_x_restrict(work.x_restrict())
+            && !self.muted_user_ids.contains(&work.user_id())
+            && !work
+                .tags()
+                .iter()
+                .any(|tag| self.muted_tags.contains(tag.name()))
+    }
+
+    /// Values pixiv adds later are let through only if everything they could stand for is allowed.
+    fn allows_ai_type(&self, ai_type: AiType) -> bool {
+        match ai_type {
+            AiType::Unspecified | AiType::NotAiGenerated => true,
+            AiType::AiGenerated | AiType::Unknown(_) => self.allow_ai,
+        }
+    }
+
+    fn allows_x_restrict(&self, x_restrict: XRestrict) -> bool {
+        match x_restrict {
+            XRestrict::AllAges => true,
+            XRestrict::R18 => self.allow_r18,
+            XRestrict::R18G => self.allow_r18g,
+            XRestrict::Unknown(_) => self.allow_r18 && self.allow_r18g,
+        }
+    }
+
+    /// Remove the works that do not pass from `page`, keeping their order.
+    pub fn apply<P>(&self, page: &mut P) -> FilterReport
+    where
+        P: Paginated,
+        P::Item: FilterableWork,
+    {
+        let items = page.items_mut();
+        let before = items.len();
+        items.retain(|work| self.allows(work));
+        FilterReport {
+            kept: items.len(),
+            dropped: before - items.len(),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use url::Url;
+
+/// Decode `null` like a missing field, falling back to the default value.
+pub(crate) fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Decode a `next_url`, which is `null` or `""` on the last page depending on the endpoint.
+pub(crate) fn optional_url<'de, D>(deserializer: D) -> Result<Option<Url>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(url) if !url.is_empty() => Url::parse(&url).map(Some).map_err(D::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ObjectOrEmpty<T> {
+    Object(T),
+    Empty {},
+}
+
+/// Decode an optional object that some responses send as `{}` instead of `null`.
+pub(crate) fn empty_object_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(
+        match Option::<ObjectOrEmpty<T>>::deserialize(deserializer)? {
+            Some(ObjectOrEmpty::Object(value)) => Some(value),
+            _ => None,
+        },
+    )
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    String(String),
+    List(Vec<String>),
+}
+
+/// Decode a string that some responses wrap in a list.
+pub(crate) fn string_or_first<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<StringOrList>::deserialize(deserializer)? {
+        Some(StringOrList::String(value)) => Some(value),
+        Some(StringOrList::List(values)) => values.into_iter().next(),
+        None => None,
+    })
+}
+
+/// Decode a field only some endpoints send, keeping `null` apart from a missing field.
+/// Paired with `default` and `skip_serializing_if = "Option::is_none"`, so the model re-serializes
+/// to the fields that were sent and strict decoding works with every endpoint.
+pub(crate) fn sent<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// `sent` for an optional object that may come as `{}`, see `empty_object_as_none`.
+pub(crate) fn sent_empty_object_as_none<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    empty_object_as_none(deserializer).map(Some)
+}
@@ -0,0 +1,27 @@
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// A tag the user registered on their bookmarks, with how many bookmarks use it.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BookmarkTag {
+    count: u32,
+    name: String,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl BookmarkTag {
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
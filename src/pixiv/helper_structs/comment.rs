@@ -1,13 +1,89 @@
+use crate::pixiv::de::{null_as_default, sent, sent_empty_object_as_none};
+use crate::pixiv::helper_structs::stamp::Stamp;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
 use crate::pixiv::user::User;
 
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+/// A comment on a work. The `v1` endpoints send the comment it replies to,
+/// the `v2` endpoints whether it has replies and the stamp it was posted as.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Comment {
+    #[serde(default, deserialize_with = "null_as_default")]
     comment: String,
-    date: String,
+    date: DateTime<FixedOffset>,
+    // Only sent by the `v2` endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    has_replies: Option<bool>,
     id: u64,
-    // TODO: Ignored for now...
-    // parent_comment :
+    // `{}` for top-level comments.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "sent_empty_object_as_none"
+    )]
+    parent_comment: Option<Option<Box<Comment>>>,
+    // Only sent by the `v2` endpoints, `null` for text comments.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "sent"
+    )]
+    stamp: Option<Option<Stamp>>,
     user: User,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for Comment {
+    fn rewrite_image_host(&mut self, host: &str) {
+        if let Some(parent_comment) = &mut self.parent_comment {
+            parent_comment.rewrite_image_host(host);
+        }
+        if let Some(stamp) = &mut self.stamp {
+            stamp.rewrite_image_host(host);
+        }
+        self.user.rewrite_image_host(host);
+    }
+}
+
+impl Comment {
+    /// The text of the comment, empty for stamps.
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    pub fn date(&self) -> &DateTime<FixedOffset> {
+        &self.date
+    }
+
+    /// Whether other comments reply to this one. `None` from endpoints that do not say.
+    pub fn has_replies(&self) -> Option<bool> {
+        self.has_replies
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// The comment this one replies to, if any and if the endpoint sends it.
+    pub fn parent_comment(&self) -> Option<&Comment> {
+        self.parent_comment.as_ref()?.as_deref()
+    }
+
+    /// The stamp the comment was posted as, if any and if the endpoint sends it.
+    pub fn stamp(&self) -> Option<&Stamp> {
+        self.stamp.as_ref()?.as_ref()
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
@@ -1,38 +1,55 @@
-use crate::enums::ContentType;
-
+use crate::constants::WEB_URL;
+use crate::enums::{AiType, ContentType, SanityLevel, XRestrict};
+use crate::pixiv::de::{null_as_default, sent};
 use crate::pixiv::helper_structs::image_url::ImageUrl;
 use crate::pixiv::helper_structs::meta_page::MetaPage;
 use crate::pixiv::helper_structs::series::Series;
 use crate::pixiv::helper_structs::single_page_meta::SingleMetaPage;
 use crate::pixiv::helper_structs::tag::Tag;
+use crate::pixiv::image_host::RewriteImageHost;
 use crate::pixiv::result::illustration_proxy::IllustrationProxy;
+use crate::pixiv::schema::ExtraFields;
 use crate::pixiv::user::User;
 
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
 /// Struct representations of a PixivClient illustration.
-/// TODO: We need to verify this struct, handles nullable types (possibly introduce a default value)
-/// and maybe stuffs that are not known (i.e. we have not encountered that property)
+/// Nullable text and list fields decode to their empty value, unknown fields end up in `extra`.
 #[derive(Deserialize, Serialize, Debug)]
 pub struct Illustration {
+    #[serde(default, deserialize_with = "null_as_default")]
     caption: String,
-    create_date: String,
+    create_date: DateTime<FixedOffset>,
     height: u32,
     width: u32,
     id: u32,
+    // Not sent for works older than the AI labels.
+    #[serde(
+        default,
+        deserialize_with = "sent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    illust_ai_type: Option<AiType>,
     image_urls: ImageUrl,
     is_bookmarked: bool,
     is_muted: bool,
+    #[serde(default, deserialize_with = "null_as_default")]
     meta_pages: Vec<MetaPage>,
     meta_single_page: Option<SingleMetaPage>,
     page_count: u32,
     restrict: u32,
-    sanity_level: u32,
+    sanity_level: SanityLevel,
     series: Option<Series>,
+    #[serde(default, deserialize_with = "null_as_default")]
     tags: Vec<Tag>,
+    #[serde(default, deserialize_with = "null_as_default")]
     title: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     tools: Vec<String>, // This should be an enum because we all the possible tools.
     total_bookmarks: u32,
+    // Only sent by the detail endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
     total_comments: Option<u32>,
     total_view: u32,
     #[serde(rename = "type")]
@@ -40,7 +57,9 @@ pub struct Illustration {
     // TODO: This should be borrowed?
     user: User,
     visible: bool,
-    x_restrict: u32,
+    x_restrict: XRestrict,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
 }
 
 /// Convert `IllustrationProxy` to `Illustration`
@@ -50,7 +69,174 @@ impl From<IllustrationProxy> for Illustration {
     }
 }
 
+impl RewriteImageHost for Illustration {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.image_urls.rewrite_image_host(host);
+        self.meta_pages.rewrite_image_host(host);
+        self.meta_single_page.rewrite_image_host(host);
+        self.user.rewrite_image_host(host);
+    }
+}
+
 impl Illustration {
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn create_date(&self) -> &DateTime<FixedOffset> {
+        &self.create_date
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// `AiType::Unspecified` if pixiv did not send it.
+    pub fn illust_ai_type(&self) -> AiType {
+        self.illust_ai_type.unwrap_or_default()
+    }
+
+    /// Whether the author marked the work as AI-generated.
+    pub fn is_ai_generated(&self) -> bool {
+        self.illust_ai_type() == AiType::AiGenerated
+    }
+
+    pub fn image_urls(&self) -> &ImageUrl {
+        &self.image_urls
+    }
+
+    pub fn is_bookmarked(&self) -> bool {
+        self.is_bookmarked
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.is_muted
+    }
+
+    pub fn meta_pages(&self) -> &[MetaPage] {
+        &self.meta_pages
+    }
+
+    pub fn meta_single_page(&self) -> Option<&SingleMetaPage> {
+        self.meta_single_page.as_ref()
+    }
+
+    pub fn page_count(&self) -> u32 {
+        self.page_count
+    }
+
+    pub fn restrict(&self) -> u32 {
+        self.restrict
+    }
+
+    pub fn sanity_level(&self) -> SanityLevel {
+        self.sanity_level
+    }
+
+    pub fn series(&self) -> Option<&Series> {
+        self.series.as_ref()
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn tools(&self) -> &[String] {
+        &self.tools
+    }
+
+    pub fn total_bookmarks(&self) -> u32 {
+        self.total_bookmarks
+    }
+
+    pub fn total_comments(&self) -> Option<u32> {
+        self.total_comments
+    }
+
+    pub fn total_view(&self) -> u32 {
+        self.total_view
+    }
+
+    pub fn content_type(&self) -> &ContentType {
+        &self.content_type
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn x_restrict(&self) -> XRestrict {
+        self.x_restrict
+    }
+
+    /// Whether the illustration is marked R-18.
+    pub fn is_r18(&self) -> bool {
+        self.x_restrict == XRestrict::R18
+    }
+
+    /// Whether the illustration is marked R-18G.
+    pub fn is_r18g(&self) -> bool {
+        self.x_restrict == XRestrict::R18G
+    }
+
+    pub fn is_multi_page(&self) -> bool {
+        self.page_count > 1
+    }
+
+    /// The image urls of every page, in order.
+    /// Single page illustrations only have their top-level `image_urls`.
+    pub fn pages(&self) -> impl Iterator<Item = &ImageUrl> {
+        let single = if self.meta_pages.is_empty() {
+            Some(&self.image_urls)
+        } else {
+            None
+        };
+        single
+            .into_iter()
+            .chain(self.meta_pages.iter().map(MetaPage::image_urls))
+    }
+
+    /// The original upload of every page, in order.
+    pub fn original_urls(&self) -> Vec<&str> {
+        match self
+            .meta_single_page
+            .as_ref()
+            .and_then(SingleMetaPage::original_image_url)
+        {
+            Some(url) => vec![url],
+            None => self
+                .meta_pages
+                .iter()
+                .filter_map(|page| page.image_urls().original.as_deref())
+                .collect(),
+        }
+    }
+
+    pub fn tag_names(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(Tag::name)
+    }
+
+    /// Link to the illustration on the pixiv website.
+    pub fn web_url(&self) -> String {
+        format!("{}/artworks/{}", WEB_URL, self.id)
+    }
+
     pub fn download(&self, client: &reqwest::Client, path: &std::path::Path) {
         self.image_urls
             .clone()
@@ -85,4 +271,9 @@ impl Illustration {
                 std::io::copy(&mut response, &mut dest).unwrap();
             })
     }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
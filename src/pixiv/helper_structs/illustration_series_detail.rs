@@ -0,0 +1,91 @@
+use crate::constants::WEB_URL;
+use crate::pixiv::de::null_as_default;
+use crate::pixiv::helper_structs::image_url::ImageUrl;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+use crate::pixiv::user::User;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Struct representation of an illustration or manga series.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct IllustrationSeriesDetail {
+    #[serde(default, deserialize_with = "null_as_default")]
+    caption: String,
+    cover_image_urls: ImageUrl,
+    create_date: DateTime<FixedOffset>,
+    height: u32,
+    id: u32,
+    series_work_count: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
+    title: String,
+    user: User,
+    watchlist_added: bool,
+    width: u32,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for IllustrationSeriesDetail {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.cover_image_urls.rewrite_image_host(host);
+        self.user.rewrite_image_host(host);
+    }
+}
+
+impl IllustrationSeriesDetail {
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn cover_image_urls(&self) -> &ImageUrl {
+        &self.cover_image_urls
+    }
+
+    pub fn create_date(&self) -> &DateTime<FixedOffset> {
+        &self.create_date
+    }
+
+    /// Height of the cover image.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// How many works the series holds.
+    pub fn series_work_count(&self) -> u32 {
+        self.series_work_count
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Whether the series is on the logged in user's watchlist.
+    pub fn watchlist_added(&self) -> bool {
+        self.watchlist_added
+    }
+
+    /// Width of the cover image.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Link to the series on the pixiv website.
+    pub fn web_url(&self) -> String {
+        format!("{}/user/{}/series/{}", WEB_URL, self.user.id(), self.id)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
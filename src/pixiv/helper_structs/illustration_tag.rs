@@ -1,4 +1,7 @@
 use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
 use serde::{Deserialize, Serialize};
 
 /// IllustrationTag
@@ -7,4 +10,31 @@ pub struct IllustrationTag {
     illust: Illustration,
     tag: String,
     translate_name: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for IllustrationTag {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illust.rewrite_image_host(host);
+    }
+}
+
+impl IllustrationTag {
+    pub fn illust(&self) -> &Illustration {
+        &self.illust
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn translate_name(&self) -> Option<&str> {
+        self.translate_name.as_deref()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
@@ -1,11 +1,74 @@
+use crate::pixiv::helper_structs::pximg_url::PximgUrl;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
+/// Renditions of an image. Which ones pixiv sends depends on the endpoint, so all of them are optional.
 pub struct ImageUrl {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub large: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub medium: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub small: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub square_medium: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    pub extra: ExtraFields,
+}
+
+impl ImageUrl {
+    /// Decompose the first rendition that looks like an `i.pximg.net` illustration URL.
+    /// The original is preferred since it is the only one that carries the real extension.
+    pub fn pximg_url(&self) -> Option<PximgUrl> {
+        [
+            &self.original,
+            &self.large,
+            &self.medium,
+            &self.square_medium,
+            &self.small,
+        ]
+        .iter()
+        .filter_map(|url| url.as_ref())
+        .find_map(|url| PximgUrl::parse(url))
+    }
+
+    /// Derive the renditions of another page of the same illustration.
+    /// `original` is only filled in if its extension is known.
+    pub fn for_page(&self, page: u32) -> Option<ImageUrl> {
+        self.pximg_url().map(|url| {
+            let url = url.with_page(page);
+            ImageUrl {
+                large: Some(url.large()),
+                medium: Some(url.medium()),
+                small: None,
+                square_medium: Some(url.square_medium()),
+                original: url.original(),
+                extra: ExtraFields::new(),
+            }
+        })
+    }
+
+    /// Every URL the original upload could live at, see `PximgUrl::original_candidates`.
+    pub fn original_url_candidates(&self) -> Vec<String> {
+        self.pximg_url()
+            .map(|url| url.original_candidates())
+            .unwrap_or_default()
+    }
+}
+
+impl RewriteImageHost for ImageUrl {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.large.rewrite_image_host(host);
+        self.medium.rewrite_image_host(host);
+        self.small.rewrite_image_host(host);
+        self.square_medium.rewrite_image_host(host);
+        self.original.rewrite_image_host(host);
+    }
 }
 
 impl IntoIterator for ImageUrl {
@@ -35,6 +98,7 @@ impl Iterator for ImageUrlIterator {
                 1 => self.url.medium.take(),
                 2 => self.url.large.take(),
                 3 => self.url.square_medium.take(),
+                4 => self.url.original.take(),
                 _ => return None,
             };
             self.index += 1;
@@ -1,8 +1,29 @@
 use crate::pixiv::helper_structs::image_url::ImageUrl;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
 
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MetaPage {
     image_urls: ImageUrl,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for MetaPage {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.image_urls.rewrite_image_host(host);
+    }
+}
+
+impl MetaPage {
+    pub fn image_urls(&self) -> &ImageUrl {
+        &self.image_urls
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
@@ -1,8 +1,19 @@
+pub mod bookmark_tag;
 pub mod comment;
 pub mod illustration;
+pub mod illustration_series_detail;
 pub mod illustration_tag;
 pub mod image_url;
 pub mod meta_page;
+pub mod novel;
+pub mod novel_series_detail;
+pub mod profile_publicity;
+pub mod pximg_url;
 pub mod series;
 pub mod single_page_meta;
+pub mod spotlight_article;
+pub mod stamp;
 pub mod tag;
+pub mod user_preview;
+pub mod user_profile;
+pub mod workspace;
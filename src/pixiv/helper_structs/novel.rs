@@ -0,0 +1,192 @@
+use crate::constants::WEB_URL;
+use crate::enums::{AiType, XRestrict};
+use crate::pixiv::de::{empty_object_as_none, null_as_default, sent};
+use crate::pixiv::helper_structs::image_url::ImageUrl;
+use crate::pixiv::helper_structs::series::Series;
+use crate::pixiv::helper_structs::tag::Tag;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::result::novel_proxy::NovelProxy;
+use crate::pixiv::schema::ExtraFields;
+use crate::pixiv::user::User;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Struct representation of a pixiv novel. The text itself is fetched separately.
+/// Nullable text and list fields decode to their empty value, unknown fields end up in `extra`.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Novel {
+    #[serde(default, deserialize_with = "null_as_default")]
+    caption: String,
+    create_date: DateTime<FixedOffset>,
+    id: u32,
+    image_urls: ImageUrl,
+    is_bookmarked: bool,
+    is_muted: bool,
+    is_mypixiv_only: bool,
+    is_original: bool,
+    is_x_restricted: bool,
+    // Not sent for works older than the AI labels.
+    #[serde(
+        default,
+        deserialize_with = "sent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    novel_ai_type: Option<AiType>,
+    page_count: u32,
+    restrict: u32,
+    // pixiv sends `{}` for novels outside a series.
+    #[serde(default, deserialize_with = "empty_object_as_none")]
+    series: Option<Series>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    tags: Vec<Tag>,
+    text_length: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
+    title: String,
+    total_bookmarks: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_comments: Option<u32>,
+    total_view: u32,
+    user: User,
+    visible: bool,
+    x_restrict: XRestrict,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// Convert `NovelProxy` to `Novel`
+impl From<NovelProxy> for Novel {
+    fn from(proxy: NovelProxy) -> Self {
+        proxy.into_inner()
+    }
+}
+
+impl RewriteImageHost for Novel {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.image_urls.rewrite_image_host(host);
+        self.user.rewrite_image_host(host);
+    }
+}
+
+impl Novel {
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn create_date(&self) -> &DateTime<FixedOffset> {
+        &self.create_date
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The cover image.
+    pub fn image_urls(&self) -> &ImageUrl {
+        &self.image_urls
+    }
+
+    pub fn is_bookmarked(&self) -> bool {
+        self.is_bookmarked
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.is_muted
+    }
+
+    pub fn is_mypixiv_only(&self) -> bool {
+        self.is_mypixiv_only
+    }
+
+    /// Whether the author marked the novel as original work rather than fan fiction.
+    pub fn is_original(&self) -> bool {
+        self.is_original
+    }
+
+    pub fn is_x_restricted(&self) -> bool {
+        self.is_x_restricted
+    }
+
+    /// `AiType::Unspecified` if pixiv did not send it.
+    pub fn novel_ai_type(&self) -> AiType {
+        self.novel_ai_type.unwrap_or_default()
+    }
+
+    /// Whether the author marked the novel as AI-generated.
+    pub fn is_ai_generated(&self) -> bool {
+        self.novel_ai_type() == AiType::AiGenerated
+    }
+
+    pub fn page_count(&self) -> u32 {
+        self.page_count
+    }
+
+    pub fn restrict(&self) -> u32 {
+        self.restrict
+    }
+
+    pub fn series(&self) -> Option<&Series> {
+        self.series.as_ref()
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn tag_names(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(Tag::name)
+    }
+
+    /// Length of the text in characters.
+    pub fn text_length(&self) -> u32 {
+        self.text_length
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn total_bookmarks(&self) -> u32 {
+        self.total_bookmarks
+    }
+
+    pub fn total_comments(&self) -> Option<u32> {
+        self.total_comments
+    }
+
+    pub fn total_view(&self) -> u32 {
+        self.total_view
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn x_restrict(&self) -> XRestrict {
+        self.x_restrict
+    }
+
+    /// Whether the novel is marked R-18.
+    pub fn is_r18(&self) -> bool {
+        self.x_restrict == XRestrict::R18
+    }
+
+    /// Whether the novel is marked R-18G.
+    pub fn is_r18g(&self) -> bool {
+        self.x_restrict == XRestrict::R18G
+    }
+
+    /// Link to the novel on the pixiv website.
+    pub fn web_url(&self) -> String {
+        format!("{}/novel/show.php?id={}", WEB_URL, self.id)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
@@ -0,0 +1,98 @@
+use crate::constants::WEB_URL;
+use crate::enums::AiType;
+use crate::pixiv::de::null_as_default;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+use crate::pixiv::user::User;
+
+use serde::{Deserialize, Serialize};
+
+/// Struct representation of a novel series.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct NovelSeriesDetail {
+    #[serde(default, deserialize_with = "null_as_default")]
+    caption: String,
+    content_count: u32,
+    // E.g. "第2話", how far the series has come, in the app's language.
+    #[serde(default, deserialize_with = "null_as_default")]
+    display_text: String,
+    id: u32,
+    is_concluded: bool,
+    is_original: bool,
+    // Not sent for series older than the AI labels.
+    #[serde(default)]
+    novel_ai_type: AiType,
+    #[serde(default, deserialize_with = "null_as_default")]
+    title: String,
+    total_character_count: u32,
+    user: User,
+    watchlist_added: bool,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for NovelSeriesDetail {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.user.rewrite_image_host(host);
+    }
+}
+
+impl NovelSeriesDetail {
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    /// How many novels the series holds.
+    pub fn content_count(&self) -> u32 {
+        self.content_count
+    }
+
+    pub fn display_text(&self) -> &str {
+        &self.display_text
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Whether the author marked the series as finished.
+    pub fn is_concluded(&self) -> bool {
+        self.is_concluded
+    }
+
+    pub fn is_original(&self) -> bool {
+        self.is_original
+    }
+
+    pub fn novel_ai_type(&self) -> AiType {
+        self.novel_ai_type
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Length of every novel in the series together, in characters.
+    pub fn total_character_count(&self) -> u32 {
+        self.total_character_count
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Whether the series is on the logged in user's watchlist.
+    pub fn watchlist_added(&self) -> bool {
+        self.watchlist_added
+    }
+
+    /// Link to the series on the pixiv website.
+    pub fn web_url(&self) -> String {
+        format!("{}/novel/series/{}", WEB_URL, self.id)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
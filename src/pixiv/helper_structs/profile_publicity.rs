@@ -0,0 +1,49 @@
+use crate::enums::Publicity;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// Who can see each of the personal fields of a `UserProfile`.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ProfilePublicity {
+    birth_day: Publicity,
+    birth_year: Publicity,
+    gender: Publicity,
+    job: Publicity,
+    pawoo: bool,
+    region: Publicity,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl ProfilePublicity {
+    pub fn birth_day(&self) -> &Publicity {
+        &self.birth_day
+    }
+
+    pub fn birth_year(&self) -> &Publicity {
+        &self.birth_year
+    }
+
+    pub fn gender(&self) -> &Publicity {
+        &self.gender
+    }
+
+    pub fn job(&self) -> &Publicity {
+        &self.job
+    }
+
+    /// Whether the pawoo link is shown.
+    pub fn pawoo(&self) -> bool {
+        self.pawoo
+    }
+
+    pub fn region(&self) -> &Publicity {
+        &self.region
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
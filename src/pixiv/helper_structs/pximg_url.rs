@@ -0,0 +1,181 @@
+use crate::constants::PIXIV_REFERER;
+
+/// Extensions Pixiv uses for original uploads, in the order they are probed.
+pub const ORIGINAL_EXTENSIONS: [&str; 3] = ["png", "jpg", "gif"];
+
+const MASTER_SEGMENT: &str = "/img-master/img/";
+const ORIGINAL_SEGMENT: &str = "/img-original/img/";
+const CUSTOM_THUMB_SEGMENT: &str = "/custom-thumb/img/";
+
+/// A decomposed `i.pximg.net` illustration URL.
+///
+/// Every rendition Pixiv serves for a page shares the same upload timestamp and illustration id,
+/// so any of them (thumbnail, master or original) is enough to derive the others.
+/// The host is kept as-is, so URLs that were already rewritten to a mirror still parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PximgUrl {
+    base: String,
+    date_path: String,
+    illust_id: u32,
+    page: u32,
+    original_extension: Option<String>,
+}
+
+impl PximgUrl {
+    /// Parse a rendition URL such as `https://i.pximg.net/c/600x1200_90/img-master/img/2019/07/10/00/00/05/75523989_p0_master1200.jpg`.
+    /// Returns `None` if the URL does not follow the `img-master`/`img-original` layout.
+    pub fn parse(url: &str) -> Option<PximgUrl> {
+        let (prefix, rest, is_original) = [
+            (MASTER_SEGMENT, false),
+            (ORIGINAL_SEGMENT, true),
+            (CUSTOM_THUMB_SEGMENT, false),
+        ]
+        .iter()
+        .find_map(|(segment, is_original)| {
+            url.find(segment)
+                .map(|idx| (&url[..idx], &url[idx + segment.len()..], *is_original))
+        })?;
+
+        // Resized renditions live under `/c/<size>/`, which is not part of the base.
+        let base = match prefix.rfind("/c/") {
+            Some(idx) if !prefix[idx + 3..].contains('/') => &prefix[..idx],
+            _ => prefix,
+        };
+
+        let rest = rest.split(['?', '#']).next()?;
+        let (date_path, file_name) = rest.split_at(rest.rfind('/')?);
+        let file_name = &file_name[1..];
+        let (stem, extension) = file_name.split_at(file_name.rfind('.')?);
+
+        let mut parts = stem.split('_');
+        let illust_id = parts.next()?.parse().ok()?;
+        let page = parts.next()?.strip_prefix("p")?.parse().ok()?;
+
+        Some(PximgUrl {
+            base: base.to_string(),
+            date_path: date_path.to_string(),
+            illust_id,
+            page,
+            original_extension: if is_original {
+                Some(extension[1..].to_string())
+            } else {
+                None
+            },
+        })
+    }
+
+    pub fn illust_id(&self) -> u32 {
+        self.illust_id
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    /// Scheme and host (plus any path prefix) the renditions are served from.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// The extension of the original upload, if it was parsed from an original URL.
+    pub fn original_extension(&self) -> Option<&str> {
+        self.original_extension.as_deref()
+    }
+
+    /// The same illustration, pointing at another page.
+    /// The original extension is dropped since pages of one work may differ.
+    pub fn with_page(&self, page: u32) -> PximgUrl {
+        PximgUrl {
+            page,
+            original_extension: if page == self.page {
+                self.original_extension.clone()
+            } else {
+                None
+            },
+            ..self.clone()
+        }
+    }
+
+    /// The same illustration, served from another base (e.g. a mirror).
+    pub fn with_base<T>(&self, base: T) -> PximgUrl
+    where
+        T: Into<String>,
+    {
+        PximgUrl {
+            base: base.into().trim_end_matches('/').to_string(),
+            ..self.clone()
+        }
+    }
+
+    fn master_path(&self, size: Option<&str>, kind: &str) -> String {
+        let size = size.map(|s| format!("/c/{}", s)).unwrap_or_default();
+        format!(
+            "{}{}{}{}/{}_p{}_{}1200.jpg",
+            self.base, size, MASTER_SEGMENT, self.date_path, self.illust_id, self.page, kind
+        )
+    }
+
+    /// The 360x360 square thumbnail, as found in `ImageUrl::square_medium`.
+    pub fn square_medium(&self) -> String {
+        self.master_path(Some("360x360_70"), "square")
+    }
+
+    /// The 540x540 thumbnail, as found in `ImageUrl::medium`.
+    pub fn medium(&self) -> String {
+        self.master_path(Some("540x540_70"), "master")
+    }
+
+    /// The 600x1200 rendition, as found in `ImageUrl::large`.
+    pub fn large(&self) -> String {
+        self.master_path(Some("600x1200_90"), "master")
+    }
+
+    /// The unscaled 1200px square crop.
+    pub fn square(&self) -> String {
+        self.master_path(None, "square")
+    }
+
+    /// The unscaled 1200px master rendition (always a JPEG).
+    pub fn master(&self) -> String {
+        self.master_path(None, "master")
+    }
+
+    /// The original upload with the given extension.
+    pub fn original_with_extension(&self, extension: &str) -> String {
+        format!(
+            "{}{}{}/{}_p{}.{}",
+            self.base, ORIGINAL_SEGMENT, self.date_path, self.illust_id, self.page, extension
+        )
+    }
+
+    /// The original upload, if its extension is known.
+    pub fn original(&self) -> Option<String> {
+        self.original_extension()
+            .map(|extension| self.original_with_extension(extension))
+    }
+
+    /// Every URL the original upload could live at.
+    /// This is a single URL if the extension is known, otherwise one per `ORIGINAL_EXTENSIONS`.
+    pub fn original_candidates(&self) -> Vec<String> {
+        match self.original() {
+            Some(url) => vec![url],
+            None => ORIGINAL_EXTENSIONS
+                .iter()
+                .map(|extension| self.original_with_extension(extension))
+                .collect(),
+        }
+    }
+
+    /// Find the original upload by sending `HEAD` requests to each candidate URL.
+    /// Returns the first URL that answers with a success status.
+    pub fn probe_original(&self, client: &reqwest::Client) -> Option<String> {
+        self.original_candidates().into_iter().find(|candidate| {
+            client
+                .head(candidate.as_str())
+                .header(reqwest::header::REFERER, PIXIV_REFERER)
+                .send()
+                .map(|response| response.status().is_success())
+                .unwrap_or(false)
+        })
+    }
+}
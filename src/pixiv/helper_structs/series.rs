@@ -1,7 +1,26 @@
+use crate::pixiv::schema::ExtraFields;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Series {
     id: u32,
     title: String,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl Series {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
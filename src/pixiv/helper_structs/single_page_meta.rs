@@ -1,6 +1,29 @@
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct SingleMetaPage {
+    #[serde(skip_serializing_if = "Option::is_none")]
     original_image_url: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for SingleMetaPage {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.original_image_url.rewrite_image_host(host);
+    }
+}
+
+impl SingleMetaPage {
+    pub fn original_image_url(&self) -> Option<&str> {
+        self.original_image_url.as_deref()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
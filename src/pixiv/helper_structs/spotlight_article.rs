@@ -0,0 +1,70 @@
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A Pixivision article, as listed in the app.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SpotlightArticle {
+    article_url: Url,
+    // E.g. "spotlight" or "inspiration", not the category articles are listed by.
+    category: String,
+    id: u32,
+    publish_date: DateTime<FixedOffset>,
+    pure_title: String,
+    subcategory_label: String,
+    thumbnail: String,
+    title: String,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for SpotlightArticle {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.thumbnail.rewrite_image_host(host);
+    }
+}
+
+impl SpotlightArticle {
+    /// The article on pixivision.net, see `spotlight::featured_illust_ids` to read it.
+    pub fn article_url(&self) -> &Url {
+        &self.article_url
+    }
+
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn publish_date(&self) -> &DateTime<FixedOffset> {
+        &self.publish_date
+    }
+
+    /// The title without its decorations.
+    pub fn pure_title(&self) -> &str {
+        &self.pure_title
+    }
+
+    /// E.g. "イラスト", in the app's language.
+    pub fn subcategory_label(&self) -> &str {
+        &self.subcategory_label
+    }
+
+    pub fn thumbnail(&self) -> &str {
+        &self.thumbnail
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
@@ -0,0 +1,35 @@
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// A sticker posted as a comment instead of text.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Stamp {
+    stamp_id: u32,
+    stamp_url: String,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for Stamp {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.stamp_url.rewrite_image_host(host);
+    }
+}
+
+impl Stamp {
+    /// The id to post the same stamp with, see `IllustrationCommentAddRequestArg::stamp`.
+    pub fn stamp_id(&self) -> u32 {
+        self.stamp_id
+    }
+
+    pub fn stamp_url(&self) -> &str {
+        &self.stamp_url
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
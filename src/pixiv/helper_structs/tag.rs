@@ -1,7 +1,36 @@
+use crate::pixiv::de::string_or_first;
+use crate::pixiv::schema::ExtraFields;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct Tag {
+    // Only sent on novels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    added_by_uploaded_user: Option<bool>,
     name: String,
-    translated_name: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "string_or_first")]
+    translated_name: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl Tag {
+    /// Whether the author added the tag, rather than a reader. Only known for novels.
+    pub fn added_by_uploaded_user(&self) -> Option<bool> {
+        self.added_by_uploaded_user
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn translated_name(&self) -> Option<&str> {
+        self.translated_name.as_deref()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
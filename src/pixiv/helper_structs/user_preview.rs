@@ -0,0 +1,51 @@
+use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+use crate::pixiv::user::User;
+
+use serde::{Deserialize, Serialize};
+
+/// A user in a listing, with a few of their latest works.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserPreview {
+    #[serde(default)]
+    illusts: Vec<Illustration>,
+    is_muted: bool,
+    #[serde(default)]
+    novels: Vec<Novel>,
+    user: User,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for UserPreview {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illusts.rewrite_image_host(host);
+        self.novels.rewrite_image_host(host);
+        self.user.rewrite_image_host(host);
+    }
+}
+
+impl UserPreview {
+    pub fn illusts(&self) -> &[Illustration] {
+        &self.illusts
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.is_muted
+    }
+
+    pub fn novels(&self) -> &[Novel] {
+        &self.novels
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
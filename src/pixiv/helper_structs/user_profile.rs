@@ -0,0 +1,202 @@
+use crate::pixiv::de::null_as_default;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Profile details and public statistics of a user, from the user detail endpoint.
+/// Fields the user left blank are empty strings or zero.
+/// Links are free text as the user typed them, the `parsed_` accessors read them as URLs.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UserProfile {
+    address_id: u32,
+    background_image_url: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    birth: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    birth_day: String,
+    birth_year: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
+    country_code: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    gender: String,
+    is_premium: bool,
+    is_using_custom_profile_image: bool,
+    #[serde(default, deserialize_with = "null_as_default")]
+    job: String,
+    job_id: u32,
+    pawoo_url: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    region: String,
+    total_follow_users: u32,
+    total_illust_bookmarks_public: u32,
+    total_illust_series: u32,
+    total_illusts: u32,
+    total_manga: u32,
+    total_mypixiv_users: u32,
+    total_novel_series: u32,
+    total_novels: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
+    twitter_account: String,
+    twitter_url: Option<String>,
+    webpage: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for UserProfile {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.background_image_url.rewrite_image_host(host);
+    }
+}
+
+impl UserProfile {
+    pub fn address_id(&self) -> u32 {
+        self.address_id
+    }
+
+    pub fn background_image_url(&self) -> Option<&str> {
+        self.background_image_url.as_deref()
+    }
+
+    /// The full birthday, if the user gave one that includes the year.
+    pub fn birthday(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.birth, "%Y-%m-%d").ok()
+    }
+
+    /// Month and day of birth as `MM-DD`, or empty.
+    pub fn birth_day(&self) -> &str {
+        &self.birth_day
+    }
+
+    /// Year of birth, or 0 if not given.
+    pub fn birth_year(&self) -> u32 {
+        self.birth_year
+    }
+
+    pub fn country_code(&self) -> &str {
+        &self.country_code
+    }
+
+    pub fn gender(&self) -> &str {
+        &self.gender
+    }
+
+    pub fn is_premium(&self) -> bool {
+        self.is_premium
+    }
+
+    pub fn is_using_custom_profile_image(&self) -> bool {
+        self.is_using_custom_profile_image
+    }
+
+    pub fn job(&self) -> &str {
+        &self.job
+    }
+
+    pub fn job_id(&self) -> u32 {
+        self.job_id
+    }
+
+    pub fn pawoo_url(&self) -> Option<&str> {
+        non_empty(&self.pawoo_url)
+    }
+
+    /// `pawoo_url` parsed as a URL, see `parsed_webpage`.
+    pub fn parsed_pawoo_url(&self) -> Option<Url> {
+        self.pawoo_url().and_then(parse_link)
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// How many users this user follows.
+    pub fn total_follow_users(&self) -> u32 {
+        self.total_follow_users
+    }
+
+    pub fn total_illust_bookmarks_public(&self) -> u32 {
+        self.total_illust_bookmarks_public
+    }
+
+    pub fn total_illust_series(&self) -> u32 {
+        self.total_illust_series
+    }
+
+    pub fn total_illusts(&self) -> u32 {
+        self.total_illusts
+    }
+
+    pub fn total_manga(&self) -> u32 {
+        self.total_manga
+    }
+
+    pub fn total_mypixiv_users(&self) -> u32 {
+        self.total_mypixiv_users
+    }
+
+    pub fn total_novel_series(&self) -> u32 {
+        self.total_novel_series
+    }
+
+    pub fn total_novels(&self) -> u32 {
+        self.total_novels
+    }
+
+    /// Illustrations, manga and novels together.
+    pub fn total_works(&self) -> u32 {
+        self.total_illusts + self.total_manga + self.total_novels
+    }
+
+    /// The twitter handle, without the `@`.
+    pub fn twitter_account(&self) -> &str {
+        &self.twitter_account
+    }
+
+    pub fn twitter_url(&self) -> Option<&str> {
+        non_empty(&self.twitter_url)
+    }
+
+    /// `twitter_url` parsed as a URL, see `parsed_webpage`.
+    pub fn parsed_twitter_url(&self) -> Option<Url> {
+        self.twitter_url().and_then(parse_link)
+    }
+
+    /// The user's website as they typed it, which need not be a URL.
+    pub fn webpage(&self) -> Option<&str> {
+        non_empty(&self.webpage)
+    }
+
+    /// `webpage` parsed as a URL, reading a bare `example.com` as `https://example.com/`.
+    /// `None` if it is not a web address.
+    pub fn parsed_webpage(&self) -> Option<Url> {
+        self.webpage().and_then(parse_link)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|value| !value.trim().is_empty())
+}
+
+fn parse_link(link: &str) -> Option<Url> {
+    let link = link.trim();
+    let url = match Url::parse(link) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            Url::parse(&format!("https://{}", link)).ok()?
+        }
+        Err(_) => return None,
+    };
+    match url.scheme() {
+        "http" | "https" if url.host_str().is_some_and(|host| host.contains('.')) => Some(url),
+        _ => None,
+    }
+}
@@ -0,0 +1,125 @@
+use crate::pixiv::de::null_as_default;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// The "Workspace" section of a profile: free-form descriptions of the user's setup.
+/// Every field is an empty string when left blank.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Workspace {
+    #[serde(default, deserialize_with = "null_as_default")]
+    chair: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    comment: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    desk: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    desktop: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    monitor: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    mouse: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    music: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pc: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    printer: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    scanner: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    tablet: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    tool: String,
+    workspace_image_url: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for Workspace {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.workspace_image_url.rewrite_image_host(host);
+    }
+}
+
+impl Workspace {
+    pub fn chair(&self) -> &str {
+        &self.chair
+    }
+
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    pub fn desk(&self) -> &str {
+        &self.desk
+    }
+
+    pub fn desktop(&self) -> &str {
+        &self.desktop
+    }
+
+    pub fn monitor(&self) -> &str {
+        &self.monitor
+    }
+
+    pub fn mouse(&self) -> &str {
+        &self.mouse
+    }
+
+    pub fn music(&self) -> &str {
+        &self.music
+    }
+
+    pub fn pc(&self) -> &str {
+        &self.pc
+    }
+
+    pub fn printer(&self) -> &str {
+        &self.printer
+    }
+
+    pub fn scanner(&self) -> &str {
+        &self.scanner
+    }
+
+    pub fn tablet(&self) -> &str {
+        &self.tablet
+    }
+
+    /// Drawing software, as typed by the user.
+    pub fn tool(&self) -> &str {
+        &self.tool
+    }
+
+    pub fn workspace_image_url(&self) -> Option<&str> {
+        self.workspace_image_url.as_deref()
+    }
+
+    /// Whether the user filled in anything at all.
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.chair,
+            &self.comment,
+            &self.desk,
+            &self.desktop,
+            &self.monitor,
+            &self.mouse,
+            &self.music,
+            &self.pc,
+            &self.printer,
+            &self.scanner,
+            &self.tablet,
+            &self.tool,
+        ]
+        .iter()
+        .all(|field| field.is_empty())
+            && self.workspace_image_url.is_none()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
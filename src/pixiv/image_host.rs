@@ -0,0 +1,81 @@
+use crate::constants::PXIMG_HOST;
+
+use std::collections::HashMap;
+
+/// Replace the `https://i.pximg.net` prefix of `url` with `host`.
+/// `host` is a base URL such as `https://i.pixiv.re` or `https://example.com/pximg`.
+/// URLs served from anywhere else (e.g. `s.pximg.net` placeholders) are returned unchanged.
+pub fn rewrite_image_host(url: &str, host: &str) -> String {
+    match url.strip_prefix(PXIMG_HOST) {
+        Some(path) if path.starts_with('/') => {
+            format!("{}{}", host.trim_end_matches('/'), path)
+        }
+        _ => url.to_string(),
+    }
+}
+
+/// Types holding image URLs that can be pointed at another image host.
+/// See `PixivClient::set_image_host`.
+pub trait RewriteImageHost {
+    /// Point every `i.pximg.net` URL in `self` at `host` instead.
+    fn rewrite_image_host(&mut self, host: &str);
+}
+
+impl RewriteImageHost for String {
+    fn rewrite_image_host(&mut self, host: &str) {
+        *self = rewrite_image_host(self, host);
+    }
+}
+
+impl<T> RewriteImageHost for Option<T>
+where
+    T: RewriteImageHost,
+{
+    fn rewrite_image_host(&mut self, host: &str) {
+        if let Some(value) = self {
+            value.rewrite_image_host(host);
+        }
+    }
+}
+
+impl<T> RewriteImageHost for Box<T>
+where
+    T: RewriteImageHost,
+{
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.as_mut().rewrite_image_host(host);
+    }
+}
+
+impl<T> RewriteImageHost for Vec<T>
+where
+    T: RewriteImageHost,
+{
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.iter_mut()
+            .for_each(|value| value.rewrite_image_host(host));
+    }
+}
+
+impl<K, V> RewriteImageHost for HashMap<K, V>
+where
+    V: RewriteImageHost,
+{
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.values_mut()
+            .for_each(|value| value.rewrite_image_host(host));
+    }
+}
+
+impl RewriteImageHost for serde_json::Value {
+    fn rewrite_image_host(&mut self, host: &str) {
+        match self {
+            serde_json::Value::String(url) => url.rewrite_image_host(host),
+            serde_json::Value::Array(values) => values.rewrite_image_host(host),
+            serde_json::Value::Object(values) => values
+                .values_mut()
+                .for_each(|value| value.rewrite_image_host(host)),
+            _ => {}
+        }
+    }
+}
@@ -1,7 +1,17 @@
 pub mod arg;
 pub mod client;
+pub mod content_filter;
+pub(crate) mod de;
 pub mod helper_structs;
+pub mod image_host;
+pub mod novel_export;
+pub mod novel_markup;
+pub mod pagination;
+pub mod ranking;
 pub mod request;
 pub mod request_builder;
 pub mod result;
+pub mod schema;
+pub mod search_query;
+pub mod spotlight;
 pub mod user;
@@ -0,0 +1,344 @@
+use super::{blocks, link_target, Block, BookNovel, EmbeddedImage, ImageSource, NovelBook};
+use crate::errors::ExportError;
+use crate::pixiv::novel_markup::Node;
+
+use chrono::Utc;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use std::collections::HashMap;
+use std::io::{Seek, Write};
+
+const CONTAINER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+const STYLE: &str = "p { margin: 0; }
+h1, h2 { margin: 1em 0; }
+.image { margin: 1em 0; text-align: center; }
+.image img { max-width: 100%; max-height: 100%; }
+";
+
+/// A chapter heading and the page it is on, for the table of contents.
+struct TocChapter {
+    href: String,
+    label: String,
+}
+
+impl NovelBook {
+    /// Write the book as an EPUB 3 archive, one XHTML file per novel page.
+    /// Ruby becomes `<ruby>`, chapters make up the table of contents and embedded images are
+    /// fetched from `images` and stored in the archive. Returns the writer once the archive is done.
+    pub fn write_epub<W, S>(&self, writer: W, images: &S) -> Result<W, ExportError>
+    where
+        W: Write + Seek,
+        S: ImageSource + ?Sized,
+    {
+        let mut zip = ZipWriter::new(writer);
+        let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        // Readers sniff the type from the first, uncompressed entry.
+        zip.start_file("mimetype", stored)
+            .map_err(ExportError::Zip)?;
+        zip.write_all(b"application/epub+zip")
+            .map_err(ExportError::Io)?;
+        zip.start_file("META-INF/container.xml", deflated)
+            .map_err(ExportError::Zip)?;
+        zip.write_all(CONTAINER.as_bytes())
+            .map_err(ExportError::Io)?;
+        zip.start_file("OEBPS/style.css", deflated)
+            .map_err(ExportError::Zip)?;
+        zip.write_all(STYLE.as_bytes()).map_err(ExportError::Io)?;
+
+        // Manifest entries as (id, href, media type, properties).
+        let mut manifest = vec![
+            (
+                "nav".to_string(),
+                "nav.xhtml".to_string(),
+                "application/xhtml+xml".to_string(),
+                Some("nav"),
+            ),
+            (
+                "style".to_string(),
+                "style.css".to_string(),
+                "text/css".to_string(),
+                None,
+            ),
+        ];
+
+        let mut image_hrefs = HashMap::new();
+        for image in self.embedded_images() {
+            if let Some(fetched) = images.fetch(&image) {
+                let href = format!("images/{}.{}", image.file_stem(), fetched.extension());
+                zip.start_file(format!("OEBPS/{}", href), stored)
+                    .map_err(ExportError::Zip)?;
+                zip.write_all(&fetched.data).map_err(ExportError::Io)?;
+                manifest.push((
+                    format!("image-{}", image.file_stem()),
+                    href.clone(),
+                    fetched.media_type,
+                    None,
+                ));
+                image_hrefs.insert(image, href);
+            }
+        }
+
+        let mut spine = Vec::new();
+        let mut toc = Vec::new();
+        let mut chapter_count = 0;
+        for (novel_index, novel) in self.novels.iter().enumerate() {
+            let pages = novel.document.pages();
+            let mut chapters = Vec::new();
+            for (page_index, page) in pages.iter().enumerate() {
+                let name = page_name(novel_index, page_index);
+                let href = format!("text/{}.xhtml", name);
+                let mut body = String::new();
+                if page_index == 0 {
+                    body.push_str(&format!("<h1>{}</h1>\n", escape(&novel.title)));
+                }
+                for block in blocks(page.nodes()) {
+                    match block {
+                        Block::Paragraph(nodes) => body.push_str(&format!(
+                            "<p>{}</p>\n",
+                            inline(&nodes, novel_index, pages.len())
+                        )),
+                        Block::Blank => body.push_str("<p><br /></p>\n"),
+                        Block::Heading(title) => {
+                            chapter_count += 1;
+                            let id = format!("chapter-{}", chapter_count);
+                            body.push_str(&format!(
+                                "<h2 id=\"{}\">{}</h2>\n",
+                                id,
+                                inline(&title, novel_index, pages.len())
+                            ));
+                            chapters.push(TocChapter {
+                                href: format!("{}#{}", href, id),
+                                label: title.iter().map(Node::plain_text).collect(),
+                            });
+                        }
+                        Block::Image(image) => body.push_str(&image_block(&image, &image_hrefs)),
+                    }
+                }
+
+                zip.start_file(format!("OEBPS/{}", href), deflated)
+                    .map_err(ExportError::Zip)?;
+                zip.write_all(self.xhtml(&novel.title, "../", &body).as_bytes())
+                    .map_err(ExportError::Io)?;
+                manifest.push((
+                    name.clone(),
+                    href,
+                    "application/xhtml+xml".to_string(),
+                    None,
+                ));
+                spine.push(name);
+            }
+            toc.push((novel, chapters));
+        }
+
+        zip.start_file("OEBPS/nav.xhtml", deflated)
+            .map_err(ExportError::Zip)?;
+        zip.write_all(self.nav(toc).as_bytes())
+            .map_err(ExportError::Io)?;
+        zip.start_file("OEBPS/content.opf", deflated)
+            .map_err(ExportError::Zip)?;
+        zip.write_all(self.package(&manifest, &spine).as_bytes())
+            .map_err(ExportError::Io)?;
+
+        zip.finish().map_err(ExportError::Zip)
+    }
+
+    fn xhtml(&self, title: &str, root: &str, body: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="{language}" lang="{language}">
+<head>
+<meta charset="UTF-8" />
+<title>{title}</title>
+<link rel="stylesheet" type="text/css" href="{root}style.css" />
+</head>
+<body>
+{body}</body>
+</html>
+"#,
+            language = escape(&self.language),
+            title = escape(title),
+            root = root,
+            body = body
+        )
+    }
+
+    /// The table of contents. A single novel lists its chapters, or its title if it has none.
+    /// Several novels are listed by title, with their chapters nested under them.
+    fn nav(&self, toc: Vec<(&BookNovel, Vec<TocChapter>)>) -> String {
+        let item = |href: &str, label: &str| {
+            format!(
+                "<li><a href=\"{}\">{}</a></li>\n",
+                escape(href),
+                escape(label)
+            )
+        };
+        let mut list = String::new();
+        for (novel_index, (novel, chapters)) in toc.iter().enumerate() {
+            let first_page = format!("text/{}.xhtml", page_name(novel_index, 0));
+            if !self.is_collection() && !chapters.is_empty() {
+                for chapter in chapters {
+                    list.push_str(&item(&chapter.href, &chapter.label));
+                }
+            } else if chapters.is_empty() {
+                list.push_str(&item(&first_page, &novel.title));
+            } else {
+                list.push_str(&format!(
+                    "<li><a href=\"{}\">{}</a>\n<ol>\n",
+                    escape(&first_page),
+                    escape(&novel.title)
+                ));
+                for chapter in chapters {
+                    list.push_str(&item(&chapter.href, &chapter.label));
+                }
+                list.push_str("</ol>\n</li>\n");
+            }
+        }
+        let body = format!(
+            "<nav epub:type=\"toc\" id=\"toc\">\n<h1>{}</h1>\n<ol>\n{}</ol>\n</nav>\n",
+            escape(&self.title),
+            list
+        );
+        self.xhtml(&self.title, "", &body)
+    }
+
+    fn package(
+        &self,
+        manifest: &[(String, String, String, Option<&str>)],
+        spine: &[String],
+    ) -> String {
+        let modified = self
+            .modified
+            .map(|modified| modified.with_timezone(&Utc))
+            .unwrap_or_default()
+            .format("%Y-%m-%dT%H:%M:%SZ");
+        let creator = self
+            .author
+            .as_ref()
+            .map(|author| format!("<dc:creator>{}</dc:creator>\n", escape(author)))
+            .unwrap_or_default();
+        let items = manifest
+            .iter()
+            .map(|(id, href, media_type, properties)| {
+                let properties = properties
+                    .map(|properties| format!(" properties=\"{}\"", properties))
+                    .unwrap_or_default();
+                format!(
+                    "<item id=\"{}\" href=\"{}\" media-type=\"{}\"{} />\n",
+                    escape(id),
+                    escape(href),
+                    escape(media_type),
+                    properties
+                )
+            })
+            .collect::<String>();
+        let itemrefs = spine
+            .iter()
+            .map(|id| format!("<itemref idref=\"{}\" />\n", escape(id)))
+            .collect::<String>();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id" xml:lang="{language}">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+<dc:identifier id="book-id">{identifier}</dc:identifier>
+<dc:title>{title}</dc:title>
+<dc:language>{language}</dc:language>
+{creator}<meta property="dcterms:modified">{modified}</meta>
+</metadata>
+<manifest>
+{items}</manifest>
+<spine>
+{itemrefs}</spine>
+</package>
+"#,
+            language = escape(&self.language),
+            identifier = escape(&self.identifier()),
+            title = escape(&self.title),
+            creator = creator,
+            modified = modified,
+            items = items,
+            itemrefs = itemrefs
+        )
+    }
+}
+
+/// The name of the file holding a page, both counted from 0. File names count from 1.
+fn page_name(novel_index: usize, page_index: usize) -> String {
+    format!("novel-{}-page-{}", novel_index + 1, page_index + 1)
+}
+
+fn inline(nodes: &[Node], novel_index: usize, page_count: usize) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape(text)),
+            Node::Ruby { base, reading } => out.push_str(&format!(
+                "<ruby>{}<rt>{}</rt></ruby>",
+                escape(base),
+                escape(reading)
+            )),
+            Node::JumpUri { text, url } => match link_target(url) {
+                Some(url) => out.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(&url),
+                    escape(text)
+                )),
+                None => out.push_str(&escape(text)),
+            },
+            Node::JumpPage(page) if *page >= 1 && (*page as usize) <= page_count => {
+                out.push_str(&format!(
+                    "<a href=\"{}.xhtml\">p. {}</a>",
+                    page_name(novel_index, *page as usize - 1),
+                    page
+                ))
+            }
+            Node::JumpPage(page) => out.push_str(&format!("p. {}", page)),
+            Node::Chapter(_) | Node::PixivImage { .. } | Node::UploadedImage { .. } => {}
+        }
+    }
+    out
+}
+
+/// The image if it was fetched, a link to it on pixiv if not, and nothing if there is no link.
+fn image_block(image: &EmbeddedImage, hrefs: &HashMap<EmbeddedImage, String>) -> String {
+    match (hrefs.get(image), image.web_url()) {
+        (Some(href), _) => format!(
+            "<div class=\"image\"><img src=\"../{}\" alt=\"\" /></div>\n",
+            escape(href)
+        ),
+        (None, Some(url)) => format!(
+            "<p class=\"image\"><a href=\"{}\">{}</a></p>\n",
+            escape(&url),
+            escape(&url)
+        ),
+        (None, None) => String::new(),
+    }
+}
+
+/// Escape text for XHTML and XML attributes.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            // Not allowed in XML at all.
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,102 @@
+use super::{blocks, link_target, Block, EmbeddedImage, NovelBook};
+use crate::pixiv::novel_markup::Node;
+
+impl NovelBook {
+    /// Render the book as plain Markdown.
+    /// Every line of the novels becomes a paragraph and pages are separated by a rule.
+    /// Ruby is written after its base in parentheses, `漢字（かんじ）`, and pixiv illustrations
+    /// become links to the illustration. Uploaded images are left out.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}\n", escape(&self.title));
+        if let Some(author) = &self.author {
+            out.push_str(&format!("\n{}\n", escape(author)));
+        }
+        let chapter_level = if self.is_collection() { "###" } else { "##" };
+
+        for novel in &self.novels {
+            if self.is_collection() {
+                out.push_str(&format!("\n## {}\n", escape(&novel.title)));
+            }
+            for (index, page) in novel.document.pages().iter().enumerate() {
+                if index > 0 {
+                    out.push_str("\n---\n");
+                }
+                for block in blocks(page.nodes()) {
+                    match block {
+                        Block::Paragraph(nodes) => {
+                            out.push('\n');
+                            out.push_str(&inline(&nodes));
+                            out.push('\n');
+                        }
+                        Block::Blank => {}
+                        Block::Heading(title) => {
+                            out.push_str(&format!("\n{} {}\n", chapter_level, inline(&title)));
+                        }
+                        Block::Image(image) => {
+                            if let Some(link) = image_link(&image) {
+                                out.push('\n');
+                                out.push_str(&link);
+                                out.push('\n');
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+fn inline(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape(text)),
+            Node::Ruby { base, reading } => {
+                out.push_str(&format!("{}（{}）", escape(base), escape(reading)))
+            }
+            Node::JumpUri { text, url } => match link_target(url) {
+                Some(url) => out.push_str(&format!(
+                    "[{}](<{}>)",
+                    escape(text),
+                    url.replace('>', "%3E")
+                )),
+                None => out.push_str(&escape(text)),
+            },
+            Node::JumpPage(page) => out.push_str(&format!("p. {}", page)),
+            Node::Chapter(_) | Node::PixivImage { .. } | Node::UploadedImage { .. } => {}
+        }
+    }
+    out
+}
+
+fn image_link(image: &EmbeddedImage) -> Option<String> {
+    let label = match image {
+        EmbeddedImage::Pixiv {
+            illust_id,
+            page: Some(page),
+        } => format!("Illustration {}, page {}", illust_id, page),
+        EmbeddedImage::Pixiv { illust_id, .. } => format!("Illustration {}", illust_id),
+        EmbeddedImage::Uploaded { .. } => return None,
+    };
+    image.web_url().map(|url| format!("[{}](<{}>)", label, url))
+}
+
+/// Escape the characters Markdown could read as formatting.
+/// Characters that only matter at the start of a line are escaped there.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_[]<>#|~!".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    let digits = out.find(|c: char| !c.is_ascii_digit()).unwrap_or(out.len());
+    if out.starts_with(['-', '+', '=']) {
+        out.insert(0, '\\');
+    } else if digits > 0 && out[digits..].starts_with(['.', ')']) {
+        out.insert(digits, '\\');
+    }
+    out
+}
@@ -0,0 +1,407 @@
+//! Offline copies of novels, as EPUB 3 or Markdown.
+
+mod epub;
+mod markdown;
+
+use crate::constants::{PIXIV_REFERER, WEB_URL};
+use crate::pixiv::client::PixivClient;
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::novel_markup::{Node, NovelDocument};
+use crate::pixiv::request_builder::PixivRequestBuilder;
+use crate::pixiv::result::illustration_proxy::IllustrationProxy;
+
+use chrono::{DateTime, FixedOffset};
+
+use std::collections::HashMap;
+use std::io::Read;
+use url::Url;
+
+/// An image a novel refers to in its markup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmbeddedImage {
+    /// `[pixivimage:id-page]`. Pages are counted from 1, `None` is the first page.
+    Pixiv { illust_id: u32, page: Option<u32> },
+    /// `[uploadedimage:id]`.
+    Uploaded { image_id: u64 },
+}
+
+impl EmbeddedImage {
+    /// Where a reader can see the image if it could not be embedded.
+    pub fn web_url(&self) -> Option<String> {
+        match self {
+            EmbeddedImage::Pixiv { illust_id, .. } => {
+                Some(format!("{}/artworks/{}", WEB_URL, illust_id))
+            }
+            EmbeddedImage::Uploaded { .. } => None,
+        }
+    }
+
+    /// The file name the image is stored under in an EPUB, without extension.
+    fn file_stem(&self) -> String {
+        match self {
+            EmbeddedImage::Pixiv { illust_id, page } => {
+                format!("pixiv-{}-{}", illust_id, page.unwrap_or(1))
+            }
+            EmbeddedImage::Uploaded { image_id } => format!("uploaded-{}", image_id),
+        }
+    }
+}
+
+/// A downloaded image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    /// E.g. `image/jpeg`.
+    pub media_type: String,
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    /// Guess the media type from the extension of the URL or file name the image came from.
+    pub fn from_path<T>(path: &str, data: T) -> Image
+    where
+        T: Into<Vec<u8>>,
+    {
+        let media_type = match path.rsplit('.').next() {
+            Some("jpg") | Some("jpeg") => "image/jpeg",
+            Some("png") => "image/png",
+            Some("gif") => "image/gif",
+            Some("webp") => "image/webp",
+            _ => "application/octet-stream",
+        };
+        Image {
+            media_type: media_type.to_string(),
+            data: data.into(),
+        }
+    }
+
+    fn extension(&self) -> &str {
+        match self.media_type.as_str() {
+            "image/jpeg" => "jpg",
+            "image/png" => "png",
+            "image/gif" => "gif",
+            "image/webp" => "webp",
+            _ => "bin",
+        }
+    }
+}
+
+/// Where an exporter gets the images a novel embeds.
+/// Images it cannot provide are replaced by a link, or left out if there is nowhere to link to.
+pub trait ImageSource {
+    fn fetch(&self, image: &EmbeddedImage) -> Option<Image>;
+}
+
+/// Export without images.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoImages;
+
+impl ImageSource for NoImages {
+    fn fetch(&self, _image: &EmbeddedImage) -> Option<Image> {
+        None
+    }
+}
+
+/// Images downloaded beforehand.
+impl ImageSource for HashMap<EmbeddedImage, Image> {
+    fn fetch(&self, image: &EmbeddedImage) -> Option<Image> {
+        self.get(image).cloned()
+    }
+}
+
+/// Download illustrations from pixiv, in their large rendition.
+/// Uploaded images are not reachable through the app API and are left out.
+impl ImageSource for PixivClient {
+    fn fetch(&self, image: &EmbeddedImage) -> Option<Image> {
+        let (illust_id, page) = match *image {
+            EmbeddedImage::Pixiv { illust_id, page } => (illust_id, page.unwrap_or(1)),
+            EmbeddedImage::Uploaded { .. } => return None,
+        };
+        let request = PixivRequestBuilder::request_illustration(illust_id as usize);
+        let illustration = match self.execute_and_decode::<IllustrationProxy>(request) {
+            Ok(proxy) => proxy.into_inner(),
+            Err(e) => {
+                warn!(
+                    "novel export: failed to fetch illustration {}: {}",
+                    illust_id, e
+                );
+                return None;
+            }
+        };
+        let urls = illustration.pages().nth(page.saturating_sub(1) as usize)?;
+        let url = urls
+            .large
+            .as_ref()
+            .or(urls.medium.as_ref())
+            .or(urls.original.as_ref())?;
+        let mut data = Vec::new();
+        let result = self
+            .client
+            .get(url.as_str())
+            .header(reqwest::header::REFERER, PIXIV_REFERER)
+            .send()
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| e.to_string())
+            .and_then(|mut response| response.read_to_end(&mut data).map_err(|e| e.to_string()));
+        match result {
+            Ok(_) => Some(Image::from_path(url, data)),
+            Err(e) => {
+                warn!("novel export: failed to download {}: {}", url, e);
+                None
+            }
+        }
+    }
+}
+
+/// One novel of a `NovelBook`.
+#[derive(Debug, Clone)]
+pub struct BookNovel {
+    id: u32,
+    title: String,
+    document: NovelDocument,
+}
+
+impl BookNovel {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn document(&self) -> &NovelDocument {
+        &self.document
+    }
+}
+
+/// One novel, or a series of them in reading order, ready to be exported.
+///
+/// With a single novel the table of contents lists its chapters.
+/// With several, it lists the novels with their chapters nested under them.
+#[derive(Debug, Clone)]
+pub struct NovelBook {
+    identifier: Option<String>,
+    title: String,
+    author: Option<String>,
+    language: String,
+    modified: Option<DateTime<FixedOffset>>,
+    series_id: Option<u32>,
+    novels: Vec<BookNovel>,
+}
+
+impl NovelBook {
+    pub fn new<T>(title: T) -> Self
+    where
+        T: Into<String>,
+    {
+        NovelBook {
+            identifier: None,
+            title: title.into(),
+            author: None,
+            language: "ja".to_string(),
+            modified: None,
+            series_id: None,
+            novels: Vec::new(),
+        }
+    }
+
+    /// A book holding a single novel, titled after it.
+    pub fn single(novel: &Novel, document: NovelDocument) -> Self {
+        NovelBook::new(novel.title()).add_novel(novel, document)
+    }
+
+    /// A book titled after the series of `first`, with `first` as its opening novel.
+    /// Add the rest of the series in order with `add_novel`.
+    pub fn series(first: &Novel, document: NovelDocument) -> Self {
+        let title = first
+            .series()
+            .map_or(first.title(), |series| series.title());
+        NovelBook::new(title).add_novel(first, document)
+    }
+
+    /// Append a novel. The author defaults to the author of the first novel.
+    pub fn add_novel(mut self, novel: &Novel, document: NovelDocument) -> Self {
+        if self.author.is_none() {
+            self.author = Some(novel.user().name().to_string());
+        }
+        if self.series_id.is_none() {
+            self.series_id = novel.series().map(|series| series.id());
+        }
+        if self
+            .modified
+            .is_none_or(|modified| modified < *novel.create_date())
+        {
+            self.modified = Some(*novel.create_date());
+        }
+        self.novels.push(BookNovel {
+            id: novel.id(),
+            title: novel.title().to_string(),
+            document,
+        });
+        self
+    }
+
+    /// Defaults to the pixiv URL of the novel, or of the series if there are several.
+    pub fn set_identifier<T>(mut self, identifier: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.identifier = Some(identifier.into());
+        self
+    }
+
+    pub fn set_author<T>(mut self, author: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// A BCP 47 language tag, `ja` by default.
+    pub fn set_language<T>(mut self, language: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.language = language.into();
+        self
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    pub fn novels(&self) -> &[BookNovel] {
+        &self.novels
+    }
+
+    pub fn identifier(&self) -> String {
+        if let Some(identifier) = &self.identifier {
+            return identifier.clone();
+        }
+        match (self.novels.as_slice(), self.series_id) {
+            ([novel], _) => format!("{}/novel/show.php?id={}", WEB_URL, novel.id),
+            (_, Some(series_id)) => format!("{}/novel/series/{}", WEB_URL, series_id),
+            (novels, None) => format!(
+                "{}/novel/show.php?id={}",
+                WEB_URL,
+                novels.first().map_or(0, |novel| novel.id)
+            ),
+        }
+    }
+
+    /// Every image the novels embed, each once, in reading order.
+    pub fn embedded_images(&self) -> Vec<EmbeddedImage> {
+        let mut images = Vec::new();
+        let nodes = self
+            .novels
+            .iter()
+            .flat_map(|novel| novel.document.pages())
+            .flat_map(|page| page.nodes());
+        for image in nodes.filter_map(embedded_image) {
+            if !images.contains(&image) {
+                images.push(image);
+            }
+        }
+        images
+    }
+
+    /// Whether novels are headed by their own title, i.e. the book holds several.
+    fn is_collection(&self) -> bool {
+        self.novels.len() > 1
+    }
+}
+
+fn embedded_image(node: &Node) -> Option<EmbeddedImage> {
+    match *node {
+        Node::PixivImage { illust_id, page } => Some(EmbeddedImage::Pixiv { illust_id, page }),
+        Node::UploadedImage { image_id } => Some(EmbeddedImage::Uploaded { image_id }),
+        _ => None,
+    }
+}
+
+/// Where a `[[jumpuri:]]` may lead in an export: only `http` and `https` URLs, normalized.
+/// Anything else, e.g. a `javascript:` or `data:` URL, is exported as plain text.
+fn link_target(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()
+        .filter(|url| url.scheme() == "http" || url.scheme() == "https")
+        .map(String::from)
+}
+
+/// A page laid out for export. Both formats put every line of text in its own paragraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Block {
+    /// A line of text, holding only text without newlines and inline nodes.
+    Paragraph(Vec<Node>),
+    /// An empty line, which novels use to separate scenes.
+    Blank,
+    Heading(Vec<Node>),
+    Image(EmbeddedImage),
+}
+
+/// Split a page into blocks. The newline ending the line of a heading or image is not a blank line,
+/// and blank lines at the start and end of a page are left out.
+fn blocks(nodes: &[Node]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut line = Vec::new();
+    let mut after_block = false;
+
+    let end_line = |blocks: &mut Vec<Block>, line: &mut Vec<Node>, after_block: &mut bool| {
+        if !line.is_empty() {
+            blocks.push(Block::Paragraph(std::mem::take(line)));
+        } else if !*after_block {
+            blocks.push(Block::Blank);
+        }
+        *after_block = false;
+    };
+
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                for (index, segment) in text.split('\n').enumerate() {
+                    if index > 0 {
+                        end_line(&mut blocks, &mut line, &mut after_block);
+                    }
+                    if !segment.is_empty() {
+                        line.push(Node::Text(segment.to_string()));
+                    }
+                }
+            }
+            Node::Chapter(title) => {
+                if !line.is_empty() {
+                    end_line(&mut blocks, &mut line, &mut after_block);
+                }
+                blocks.push(Block::Heading(title.clone()));
+                after_block = true;
+            }
+            Node::PixivImage { .. } | Node::UploadedImage { .. } => {
+                if !line.is_empty() {
+                    end_line(&mut blocks, &mut line, &mut after_block);
+                }
+                blocks.extend(embedded_image(node).map(Block::Image));
+                after_block = true;
+            }
+            Node::Ruby { .. } | Node::JumpUri { .. } | Node::JumpPage(_) => line.push(node.clone()),
+        }
+    }
+    if !line.is_empty() {
+        blocks.push(Block::Paragraph(line));
+    }
+
+    let start = blocks
+        .iter()
+        .position(|block| *block != Block::Blank)
+        .unwrap_or(blocks.len());
+    let end = blocks
+        .iter()
+        .rposition(|block| *block != Block::Blank)
+        .map_or(start, |end| end + 1);
+    blocks.drain(end..);
+    blocks.drain(..start);
+    blocks
+}
@@ -0,0 +1,312 @@
+use std::fmt;
+
+/// One piece of a novel page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// Plain text, newlines included.
+    Text(String),
+    /// `[chapter:title]`, a heading. The title only holds inline nodes like text and ruby.
+    Chapter(Vec<Node>),
+    /// `[[rb:漢字 > かんじ]]`, a reading written above the base text.
+    Ruby { base: String, reading: String },
+    /// `[pixivimage:id]` or `[pixivimage:id-page]`, an illustration posted on pixiv.
+    /// Pages are counted from 1, like in the markup.
+    PixivImage { illust_id: u32, page: Option<u32> },
+    /// `[uploadedimage:id]`, an image uploaded with the novel.
+    UploadedImage { image_id: u64 },
+    /// `[[jumpuri:text > url]]`, a link.
+    JumpUri { text: String, url: String },
+    /// `[jump:page]`, a link to another page of the novel.
+    JumpPage(u32),
+}
+
+impl Node {
+    /// The text as read, with ruby reduced to the base text and markup left out.
+    pub fn plain_text(&self) -> String {
+        match self {
+            Node::Text(text) => text.clone(),
+            Node::Chapter(title) => title.iter().map(Node::plain_text).collect(),
+            Node::Ruby { base, .. } => base.clone(),
+            Node::JumpUri { text, .. } => text.clone(),
+            Node::PixivImage { .. } | Node::UploadedImage { .. } | Node::JumpPage(_) => {
+                String::new()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Node {
+    /// Write the node back as pixiv markup.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Node::Text(text) => f.write_str(text),
+            Node::Chapter(title) => {
+                f.write_str("[chapter:")?;
+                for node in title {
+                    write!(f, "{}", node)?;
+                }
+                f.write_str("]")
+            }
+            Node::Ruby { base, reading } => write!(f, "[[rb:{} > {}]]", base, reading),
+            Node::PixivImage {
+                illust_id,
+                page: Some(page),
+            } => write!(f, "[pixivimage:{}-{}]", illust_id, page),
+            Node::PixivImage {
+                illust_id,
+                page: None,
+            } => write!(f, "[pixivimage:{}]", illust_id),
+            Node::UploadedImage { image_id } => write!(f, "[uploadedimage:{}]", image_id),
+            Node::JumpUri { text, url } => write!(f, "[[jumpuri:{} > {}]]", text, url),
+            Node::JumpPage(page) => write!(f, "[jump:{}]", page),
+        }
+    }
+}
+
+/// One page of a novel, the text between two `[newpage]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Page {
+    nodes: Vec<Node>,
+}
+
+impl Page {
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn plain_text(&self) -> String {
+        self.nodes.iter().map(Node::plain_text).collect()
+    }
+
+    fn push_text(&mut self, text: &str) {
+        push_text(&mut self.nodes, text);
+    }
+}
+
+/// A novel body parsed from pixiv's markup.
+/// Parsing never fails: anything that is not well-formed markup is kept as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NovelDocument {
+    pages: Vec<Page>,
+}
+
+impl NovelDocument {
+    pub fn parse(text: &str) -> NovelDocument {
+        let mut pages = vec![Page::default()];
+        let mut rest = text;
+
+        while let Some(start) = rest.find('[') {
+            let page = pages.last_mut().expect("There is always a page");
+            page.push_text(&rest[..start]);
+            rest = &rest[start..];
+
+            if let Some(after) = rest.strip_prefix("[newpage]") {
+                pages.push(Page::default());
+                rest = after;
+            } else if let Some((node, after)) = parse_chapter(rest).or_else(|| parse_inline(rest)) {
+                page.nodes.push(node);
+                rest = after;
+            } else {
+                page.push_text("[");
+                rest = &rest[1..];
+            }
+        }
+        pages
+            .last_mut()
+            .expect("There is always a page")
+            .push_text(rest);
+
+        NovelDocument { pages }
+    }
+
+    /// Build a document from pages, e.g. to write markup. Adjacent text nodes are merged.
+    pub fn from_pages(pages: Vec<Vec<Node>>) -> NovelDocument {
+        let mut pages = pages
+            .into_iter()
+            .map(|nodes| {
+                let mut page = Page::default();
+                for node in nodes {
+                    match node {
+                        Node::Text(text) => page.push_text(&text),
+                        node => page.nodes.push(node),
+                    }
+                }
+                page
+            })
+            .collect::<Vec<_>>();
+        if pages.is_empty() {
+            pages.push(Page::default());
+        }
+        NovelDocument { pages }
+    }
+
+    /// Every novel has at least one, possibly empty, page.
+    pub fn pages(&self) -> &[Page] {
+        &self.pages
+    }
+
+    /// The chapter headings in order, with the index of the page they are on.
+    pub fn chapters(&self) -> impl Iterator<Item = (usize, &[Node])> {
+        self.nodes().filter_map(|(index, node)| match node {
+            Node::Chapter(title) => Some((index, title.as_slice())),
+            _ => None,
+        })
+    }
+
+    /// The pixiv illustrations embedded in the novel, as `(illust_id, page)`.
+    pub fn pixiv_images(&self) -> impl Iterator<Item = (u32, Option<u32>)> + '_ {
+        self.nodes().filter_map(|(_, node)| match node {
+            Node::PixivImage { illust_id, page } => Some((*illust_id, *page)),
+            _ => None,
+        })
+    }
+
+    /// The images uploaded with the novel, by id.
+    pub fn uploaded_images(&self) -> impl Iterator<Item = u64> + '_ {
+        self.nodes().filter_map(|(_, node)| match node {
+            Node::UploadedImage { image_id } => Some(*image_id),
+            _ => None,
+        })
+    }
+
+    /// The whole text as read, pages separated by a blank line.
+    pub fn plain_text(&self) -> String {
+        self.pages
+            .iter()
+            .map(Page::plain_text)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Write the document back as pixiv markup.
+    pub fn to_markup(&self) -> String {
+        self.to_string()
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = (usize, &Node)> {
+        self.pages
+            .iter()
+            .enumerate()
+            .flat_map(|(index, page)| page.nodes.iter().map(move |node| (index, node)))
+    }
+}
+
+impl fmt::Display for NovelDocument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, page) in self.pages.iter().enumerate() {
+            if index > 0 {
+                f.write_str("[newpage]")?;
+            }
+            for node in &page.nodes {
+                write!(f, "{}", node)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match nodes.last_mut() {
+        Some(Node::Text(last)) => last.push_str(text),
+        _ => nodes.push(Node::Text(text.to_string())),
+    }
+}
+
+/// Parse `[chapter:...]` at the start of `text`. The title may hold inline markup like ruby.
+fn parse_chapter(text: &str) -> Option<(Node, &str)> {
+    let mut rest = text.strip_prefix("[chapter:")?;
+    let mut title = Vec::new();
+    loop {
+        let end = rest.find(['[', ']'])?;
+        push_text(&mut title, &rest[..end]);
+        rest = &rest[end..];
+        if let Some(after) = rest.strip_prefix(']') {
+            return Some((Node::Chapter(title), after));
+        }
+        match parse_inline(rest) {
+            Some((node, after)) => {
+                title.push(node);
+                rest = after;
+            }
+            None => {
+                push_text(&mut title, "[");
+                rest = &rest[1..];
+            }
+        }
+    }
+}
+
+/// Parse an inline tag at the start of `text`, returning it with what follows it.
+fn parse_inline(text: &str) -> Option<(Node, &str)> {
+    if let Some((inner, rest)) = enclosed(text, "[[rb:", "]]") {
+        let (base, reading) = split_arrow(inner)?;
+        return Some((
+            Node::Ruby {
+                base: base.to_string(),
+                reading: reading.to_string(),
+            },
+            rest,
+        ));
+    }
+    if let Some((inner, rest)) = enclosed(text, "[[jumpuri:", "]]") {
+        let (text, url) = split_arrow(inner)?;
+        return Some((
+            Node::JumpUri {
+                text: text.to_string(),
+                url: url.to_string(),
+            },
+            rest,
+        ));
+    }
+    if let Some((inner, rest)) = enclosed(text, "[pixivimage:", "]") {
+        let (illust_id, page) = match inner.split_once('-') {
+            Some((illust_id, page)) => (illust_id, Some(number(page)?)),
+            None => (inner, None),
+        };
+        let illust_id = number(illust_id)?;
+        return Some((Node::PixivImage { illust_id, page }, rest));
+    }
+    if let Some((inner, rest)) = enclosed(text, "[uploadedimage:", "]") {
+        let image_id = number(inner)?;
+        return Some((Node::UploadedImage { image_id }, rest));
+    }
+    if let Some((inner, rest)) = enclosed(text, "[jump:", "]") {
+        return Some((Node::JumpPage(number(inner)?), rest));
+    }
+    None
+}
+
+/// The text between `open` at the start of `text` and the first `close` after it, on one line.
+fn enclosed<'a>(text: &'a str, open: &str, close: &str) -> Option<(&'a str, &'a str)> {
+    let rest = text.strip_prefix(open)?;
+    let end = rest.find(close)?;
+    let inner = &rest[..end];
+    if inner.contains('\n') || inner.contains('[') {
+        return None;
+    }
+    Some((inner, &rest[end + close.len()..]))
+}
+
+/// Split `left > right`, both sides trimmed and non-empty.
+fn split_arrow(inner: &str) -> Option<(&str, &str)> {
+    let (left, right) = inner.split_once('>')?;
+    let (left, right) = (left.trim(), right.trim());
+    if left.is_empty() || right.is_empty() {
+        None
+    } else {
+        Some((left, right))
+    }
+}
+
+fn number<T>(text: &str) -> Option<T>
+where
+    T: std::str::FromStr,
+{
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
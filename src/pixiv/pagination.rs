@@ -0,0 +1,57 @@
+use crate::errors::DecodeError;
+use crate::pixiv::client::PixivClient;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::request::PixivRequest;
+use crate::pixiv::request_builder::PixivRequestBuilder;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use url::Url;
+
+use std::marker::PhantomData;
+
+/// A page of results that links to the next one through `next_url`.
+pub trait Paginated {
+    type Item;
+
+    fn items(&self) -> &[Self::Item];
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item>;
+
+    /// Where the next page is, or `None` on the last page.
+    fn next_url(&self) -> Option<&Url>;
+}
+
+/// Iterator over the pages of a paginated endpoint, created with `PixivClient::pages`.
+/// Each page is fetched lazily; iteration stops after the last page or the first error.
+pub struct Pages<'a, T> {
+    client: &'a PixivClient,
+    next: Option<PixivRequest>,
+    page: PhantomData<T>,
+}
+
+impl<'a, T> Pages<'a, T> {
+    pub(crate) fn new(client: &'a PixivClient, first: PixivRequest) -> Self {
+        Pages {
+            client,
+            next: Some(first),
+            page: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Pages<'a, T>
+where
+    T: Paginated + DeserializeOwned + Serialize + RewriteImageHost,
+{
+    type Item = Result<T, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let request = self.next.take()?;
+        let page = self.client.execute_and_decode::<T>(request);
+        if let Ok(page) = &page {
+            self.next = page.next_url().map(PixivRequestBuilder::request_next_page);
+        }
+        Some(page)
+    }
+}
@@ -0,0 +1,129 @@
+//! Walking rankings over a range of past days.
+
+use crate::enums::RankingMode;
+use crate::errors::{DecodeError, RankingDateError};
+use crate::pixiv::arg::illustration_ranking_request_arg::IllustrationRankingRequestArg;
+use crate::pixiv::client::PixivClient;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::{Pages, Paginated};
+use crate::pixiv::request::PixivRequest;
+
+use chrono::NaiveDate;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Fetches the pages of one day's ranking for a `RankingCrawl`.
+/// `PixivClient` fetches them from pixiv; other sources, e.g. recorded responses, can stand in for it.
+pub trait RankingSource<T> {
+    type Pages: Iterator<Item = Result<T, DecodeError>>;
+
+    /// Every page of the ranking `first` asks for, starting with `first`.
+    fn ranking_pages(&mut self, first: PixivRequest) -> Self::Pages;
+}
+
+impl<'a, T> RankingSource<T> for &'a PixivClient
+where
+    T: Paginated + DeserializeOwned + Serialize + RewriteImageHost,
+{
+    type Pages = Pages<'a, T>;
+
+    fn ranking_pages(&mut self, first: PixivRequest) -> Self::Pages {
+        self.pages(first)
+    }
+}
+
+/// Iterator over the rankings of one mode for every day of a range, created with
+/// `PixivClient::crawl_illustration_ranking` or `PixivClient::crawl_novel_ranking`.
+///
+/// Yields `(date, rank, work)` with ranks counted from 1 each day, fetching every page of a day
+/// before moving on to the next. Days the mode has no ranking for are skipped.
+/// Iteration stops after the first error.
+pub struct RankingCrawl<S, T>
+where
+    S: RankingSource<T>,
+    T: Paginated,
+{
+    source: S,
+    mode: RankingMode,
+    request: fn(IllustrationRankingRequestArg) -> Result<PixivRequest, RankingDateError>,
+    dates: std::vec::IntoIter<NaiveDate>,
+    date: Option<NaiveDate>,
+    rank: u32,
+    pages: Option<S::Pages>,
+    items: std::vec::IntoIter<T::Item>,
+}
+
+impl<S, T> RankingCrawl<S, T>
+where
+    S: RankingSource<T>,
+    T: Paginated,
+{
+    /// Crawl the rankings of `mode` on `dates` from `source`,
+    /// building each day's first request with `request`, e.g. `PixivRequestBuilder::request_novels_ranking`.
+    pub fn new(
+        source: S,
+        mode: RankingMode,
+        dates: Vec<NaiveDate>,
+        request: fn(IllustrationRankingRequestArg) -> Result<PixivRequest, RankingDateError>,
+    ) -> Self {
+        RankingCrawl {
+            source,
+            mode,
+            request,
+            dates: dates.into_iter(),
+            date: None,
+            rank: 0,
+            pages: None,
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<S, T> Iterator for RankingCrawl<S, T>
+where
+    S: RankingSource<T>,
+    T: Paginated,
+{
+    type Item = Result<(NaiveDate, u32, T::Item), DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let (Some(item), Some(date)) = (self.items.next(), self.date) {
+                self.rank += 1;
+                return Some(Ok((date, self.rank, item)));
+            }
+            if let Some(pages) = &mut self.pages {
+                match pages.next() {
+                    Some(Ok(mut page)) => {
+                        self.items = std::mem::take(page.items_mut()).into_iter();
+                        continue;
+                    }
+                    Some(Err(e)) => {
+                        self.pages = None;
+                        self.dates = Vec::new().into_iter();
+                        return Some(Err(e));
+                    }
+                    None => self.pages = None,
+                }
+            }
+
+            let date = self.dates.next()?;
+            // The range was checked up front, this only fails for dates from elsewhere
+            // or if the day changed in Japan since.
+            let request = match IllustrationRankingRequestArg::default()
+                .set_mode(self.mode.clone())
+                .set_date(date)
+                .and_then(self.request)
+            {
+                Ok(request) => request,
+                Err(e) => {
+                    warn!("ranking crawl: skipping {}: {}", date, e);
+                    continue;
+                }
+            };
+            self.date = Some(date);
+            self.rank = 0;
+            self.pages = Some(self.source.ranking_pages(request));
+        }
+    }
+}
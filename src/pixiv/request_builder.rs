@@ -1,16 +1,27 @@
-use crate::constants::{BASE_URL, ILLUST_ID, OFFSET, RESTRICT, TAGS, USER_ID};
-use crate::enums::{Filter, RankingType, Visibility};
+use crate::constants::{
+    BASE_URL, COMMENT_ID, FOR_IOS, ILLUST_ID, NOVEL_ID, NOVEL_VIEWER_VERSION, OFFSET, RESTRICT,
+    TAGS, USER_ID,
+};
+use crate::enums::{Filter, RankingType, SpotlightCategory, Visibility};
+use crate::errors::RankingDateError;
+use crate::pixiv::arg::illustration_comment_add_request_arg::IllustrationCommentAddRequestArg;
+use crate::pixiv::arg::illustration_following_request_arg::IllustrationFollowingRequestArg;
 use crate::pixiv::arg::illustration_ranking_request_arg::IllustrationRankingRequestArg;
 use crate::pixiv::arg::illustration_search_request_arg::IllustrationSearchRequestArg;
+use crate::pixiv::arg::new_illustrations_request_arg::NewIllustrationsRequestArg;
 use crate::pixiv::arg::recommended_illustration_request_arg::RecommendedIllustrationRequestArg;
 use crate::pixiv::arg::user_bookmark_tags_illustration_request_arg::UserBookmarkTagsIllustrationRequestArg;
+use crate::pixiv::arg::user_bookmarks_illustration_request_arg::UserBookmarksIllustrationRequestArg;
 use crate::pixiv::arg::user_following_request_arg::UserFollowingRequestArgs;
+use crate::pixiv::arg::user_illustrations_request_arg::UserIllustrationsRequestArg;
+use crate::pixiv::arg::user_search_request_arg::UserSearchRequestArg;
 use crate::pixiv::request::PixivRequest;
 use crate::utils::comma_delimited;
 
 use bytes::Bytes;
 use http::{uri::Uri, HttpTryFrom, Method};
 use std::borrow::Borrow;
+use url::Url;
 
 /// PixivClient request builder. You can create this using any of the provided methods in `PixivClient`, or through `PixivRequestBuilder::new`.
 #[derive(Debug, Clone)]
@@ -27,6 +38,14 @@ impl PixivRequestBuilder {
         PixivRequest::new(Method::GET, api_uri)
     }
 
+    /// Used to build a request for the page a paginated result's `next_url` points at.
+    /// The url already carries every parameter, so the request is sent as-is.
+    pub fn request_next_page(url: &Url) -> PixivRequest {
+        let bytes = Bytes::from(url.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+    }
+
     /////////////////////////////////////////////////////////////////////
     /////
     /////                        VERSION 1 API
@@ -61,6 +80,7 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    #[deprecated(note = "The public API is gone, use `request_illustration_following` instead.")]
     pub fn feed() -> PixivRequest {
         const API_URL: &'static str = "https://public-api.secure.pixiv.net/v1/me/feeds.json";
         let url = Uri::from_static(API_URL);
@@ -141,6 +161,24 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    /// Used to build a request for the most popular results of a search.
+    /// Unlike sorting by popularity, this works without a premium account. Decodes into `PopularPreview`.
+    pub fn request_illustration_popular_preview<T>(params: T) -> PixivRequest
+    where
+        T: Into<IllustrationSearchRequestArg>,
+    {
+        let url =
+            http::Uri::try_from(format!("{}/v1/search/popular-preview/illust", BASE_URL)).unwrap();
+        params
+            .into()
+            .into_iter()
+            .fold(
+                PixivRequest::new(Method::GET, url),
+                |acc, (k, v): (&'static str, String)| acc.add_param(k, v),
+            )
+            .finish()
+    }
+
     pub fn following() -> PixivRequest {
         const API_URL: &'static str = "https://public-api.secure.pixiv.net/v1/me/following.json";
         let url = Uri::from_static(API_URL);
@@ -151,6 +189,7 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    #[deprecated(note = "The public API is gone, use `request_adding_follow` instead.")]
     pub fn following_add(user_id: usize) -> PixivRequest {
         const API_URL: &'static str =
             "https://public-api.secure.pixiv.net/v1/me/favorite-users.json";
@@ -161,6 +200,7 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    #[deprecated(note = "The public API is gone, use `request_delete_follow` instead.")]
     pub fn following_remove<B, I>(user_ids: I) -> PixivRequest
     where
         B: Borrow<usize>,
@@ -175,6 +215,7 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    #[deprecated(note = "The public API is gone, use `request_user_illustrations` instead.")]
     pub fn user_works(user_id: usize) -> PixivRequest {
         let url = format!(
             "https://public-api.secure.pixiv.net/v1/users/{}/works.json",
@@ -266,6 +307,7 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    #[deprecated(note = "The public API is gone, use `request_new_illustrations` instead.")]
     pub fn latest_works() -> PixivRequest {
         const API_URL: &'static str = "https://public-api.secure.pixiv.net/v1/works.json";
         let url = Uri::from_static(API_URL);
@@ -289,6 +331,30 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    /// Used to build a request for the Pixivision articles the app lists, newest first.
+    /// Decodes into `SpotlightArticles`.
+    pub fn request_spotlight_articles(category: SpotlightCategory, offset: usize) -> PixivRequest {
+        let uri = format!("{}/v1/spotlight/articles", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param_from_str("filter", FOR_IOS)
+            .add_param_from_str("category", category.as_str())
+            .add_param(OFFSET, offset.to_string())
+            .finish()
+    }
+
+    /// Used to build a request for an illustration or manga series and the first page of its works.
+    /// Decodes into `IllustrationSeries`, whose `next_url` leads to the following pages.
+    pub fn request_illustration_series(series_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/illust/series", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param("illust_series_id", series_id.to_string())
+            .finish()
+    }
+
     /// Used to build a request to fetch an illustration givenn its id.
     pub fn request_illustration_comments(
         illust_id: usize,
@@ -305,6 +371,57 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    /// Used to build a request for the comments on an illustration, with whether each has replies
+    /// and the stamps they were posted as. Decodes into `IllustrationComment`.
+    pub fn request_illustration_comments_v2(illust_id: usize, offset: usize) -> PixivRequest {
+        let uri = format!("{}/v2/illust/comments", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(ILLUST_ID, illust_id.to_string())
+            .add_param(OFFSET, offset.to_string())
+            .finish()
+    }
+
+    /// Used to build a request for the replies to a comment.
+    /// Decodes into `IllustrationComment`, whose `next_url` leads to the following pages.
+    pub fn request_illustration_comment_replies(comment_id: usize) -> PixivRequest {
+        let uri = format!("{}/v2/illust/comment/replies", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(COMMENT_ID, comment_id.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to post a comment on an illustration. Decodes into `CommentProxy`.
+    pub fn request_adding_illustration_comment<T>(argument: T) -> PixivRequest
+    where
+        T: Into<IllustrationCommentAddRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/illust/comment/add", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::POST, uri), |acc, (key, val)| {
+                acc.add_form(key, String::from(val))
+            })
+            .finish()
+    }
+
+    /// Used to build a request to delete a comment the logged in user posted or received.
+    pub fn request_delete_illustration_comment(comment_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/illust/comment/delete", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::POST, uri)
+            .add_form(COMMENT_ID, comment_id.to_string())
+            .finish()
+    }
+
     /// TODO: Documentation
     pub fn request_recommended_illustration<T>(argument: T) -> PixivRequest
     where
@@ -323,8 +440,9 @@ impl PixivRequestBuilder {
             .finish()
     }
 
-    /// TODO: Documentation
-    pub fn request_illustrations_ranking<T>(argument: T) -> PixivRequest
+    /// Used to build a request for the illustration ranking. Decodes into `IllustrationRanking`.
+    /// Fails if pixiv has no ranking of the mode for the date asked for.
+    pub fn request_illustrations_ranking<T>(argument: T) -> Result<PixivRequest, RankingDateError>
     where
         T: Into<IllustrationRankingRequestArg>,
     {
@@ -332,13 +450,13 @@ impl PixivRequestBuilder {
         let uri = format!("{}/v1/illust/ranking", BASE_URL);
         let bytes = Bytes::from(uri.as_str());
         let uri = Uri::from_shared(bytes).unwrap();
-        argument
-            .build()
+        Ok(argument
+            .build()?
             .iter()
             .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
                 acc.add_param(key, String::from(val))
             })
-            .finish()
+            .finish())
     }
 
     /// TODO: Documentation
@@ -351,6 +469,55 @@ impl PixivRequestBuilder {
             .finish()
     }
 
+    /// Used to build a request to find users by name or account.
+    /// Decodes into `UserPreviews`.
+    pub fn request_user_search<T>(argument: T) -> PixivRequest
+    where
+        T: Into<UserSearchRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/search/user", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
+            .finish()
+    }
+
+    /// Used to build a request to fetch a user's profile, workspace and public stats.
+    pub fn request_user_detail(user_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/user/detail", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(USER_ID, user_id.to_string())
+            .add_param_from_str("filter", Filter::ForiOS.as_str())
+            .finish()
+    }
+
+    /// Used to build a request to list a user's illustrations or manga.
+    /// Follow `next_url` (or use `PixivClient::pages`) to get the rest of the portfolio.
+    pub fn request_user_illustrations<T>(argument: T) -> PixivRequest
+    where
+        T: Into<UserIllustrationsRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/user/illusts", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
+            .finish()
+    }
+
     /////////////////////////////////////////////////////////////////////
     /////
     /////                        VERSION 2 API
@@ -368,16 +535,56 @@ impl PixivRequestBuilder {
             .finish()
     }
 
-    /// TODO: Documentation
-    pub fn request_illustration_following<T>(visibility: T) -> PixivRequest
+    /// Used to build a request for tags starting with a partially typed search word.
+    /// Decodes into `SearchAutocomplete`.
+    pub fn request_search_autocomplete<T>(word: T) -> PixivRequest
     where
-        T: Into<Visibility>,
+        T: Into<String>,
     {
-        let uri = format!("{}/v2/illust/follow", BASE_URL);
+        let uri = format!("{}/v2/search/autocomplete", BASE_URL);
         let bytes = Bytes::from(uri.as_str());
         let uri = Uri::from_shared(bytes).unwrap();
         PixivRequest::new(Method::GET, uri)
-            .add_param_from_str(RESTRICT, visibility.into().as_str())
+            .add_param("word", word)
+            .add_param_from_str("merge_plain_keyword_results", "true")
+            .finish()
+    }
+
+    /// Used to build a request for the newest works of the users the logged in user follows.
+    /// Takes the visibility of the follows, or `IllustrationFollowingRequestArg` to set an offset.
+    /// Decodes into `FollowingIllustrations`.
+    pub fn request_illustration_following<T>(argument: T) -> PixivRequest
+    where
+        T: Into<IllustrationFollowingRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v2/illust/follow", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
+            .finish()
+    }
+
+    /// Used to build a request for the newest works on pixiv. Decodes into `NewIllustrations`.
+    pub fn request_new_illustrations<T>(argument: T) -> PixivRequest
+    where
+        T: Into<NewIllustrationsRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/illust/new", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
             .finish()
     }
 
@@ -398,7 +605,7 @@ impl PixivRequestBuilder {
         let uri = Uri::from_shared(bytes).unwrap();
         PixivRequest::new(Method::POST, uri)
             .add_form(ILLUST_ID, illust_id.to_string().as_str())
-            .add_form_from_str(RESTRICT, visibility.as_str())
+            .add_form(RESTRICT, visibility.as_str())
             .add_form_from_str(TAGS, "Fate/GO")
             .finish()
     }
@@ -414,8 +621,8 @@ impl PixivRequestBuilder {
             .finish()
     }
 
-    /// TODO: Documentation
-    /// TODO: Test
+    /// Used to build a request to list the logged in user's bookmark tags.
+    /// Decodes into `BookmarkTags`.
     pub fn request_user_bookmark_tags_illustration(
         args: UserBookmarkTagsIllustrationRequestArg,
     ) -> PixivRequest {
@@ -428,8 +635,26 @@ impl PixivRequestBuilder {
             .finish()
     }
 
-    /// TODO: Documentation
-    /// TODO: Test
+    /// Used to build a request to list the illustrations a user bookmarked.
+    pub fn request_user_bookmarks_illustration<T>(argument: T) -> PixivRequest
+    where
+        T: Into<UserBookmarksIllustrationRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/user/bookmarks/illust", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
+            .finish()
+    }
+
+    /// Used to build a request to list the users someone follows.
+    /// Decodes into `UserPreviews`.
     pub fn request_user_following(args: UserFollowingRequestArgs) -> PixivRequest {
         let uri = format!("{}/v1/user/following", BASE_URL);
         let bytes = Bytes::from(uri.as_str());
@@ -440,4 +665,207 @@ impl PixivRequestBuilder {
             .add_param(OFFSET, args.offset.to_string())
             .finish()
     }
+
+    /// Used to build a request to list the users following someone.
+    /// Decodes into `UserPreviews`.
+    pub fn request_user_follower(user_id: usize, offset: usize) -> PixivRequest {
+        let uri = format!("{}/v1/user/follower", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(USER_ID, user_id.to_string())
+            .add_param(OFFSET, offset.to_string())
+            .add_param_from_str("filter", Filter::ForiOS.as_str())
+            .finish()
+    }
+
+    /// Used to build a request to list a user's mypixiv friends.
+    /// Decodes into `UserPreviews`.
+    pub fn request_user_mypixiv(user_id: usize, offset: usize) -> PixivRequest {
+        let uri = format!("{}/v1/user/mypixiv", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(USER_ID, user_id.to_string())
+            .add_param(OFFSET, offset.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to check whether the logged in user follows someone.
+    /// Decodes into `FollowDetailProxy`.
+    pub fn request_follow_detail(user_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/user/follow/detail", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(USER_ID, user_id.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to follow a user, publicly or privately.
+    /// Following someone already followed only changes the restrict.
+    pub fn request_adding_follow(user_id: usize, visibility: Visibility) -> PixivRequest {
+        let uri = format!("{}/v1/user/follow/add", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::POST, uri)
+            .add_form(USER_ID, user_id.to_string())
+            .add_form(RESTRICT, visibility.as_str())
+            .finish()
+    }
+
+    /// Used to build a request to unfollow a user.
+    pub fn request_delete_follow(user_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/user/follow/delete", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::POST, uri)
+            .add_form(USER_ID, user_id.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to fetch a novel given its id. Decodes into `NovelProxy`.
+    pub fn request_novel(novel_id: usize) -> PixivRequest {
+        let uri = format!("{}/v2/novel/detail", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(NOVEL_ID, novel_id.to_string())
+            .finish()
+    }
+
+    /// Used to build a request for the body of a novel. Decodes into `NovelText`.
+    #[deprecated(note = "pixiv has retired `/v1/novel/text`, use `request_novel_webview` instead.")]
+    pub fn request_novel_text(novel_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/novel/text", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(NOVEL_ID, novel_id.to_string())
+            .finish()
+    }
+
+    /// Used to build a request for the novel viewer page, which holds the body of a novel.
+    /// The page is HTML: read it with `NovelWebview::payload`, then decode into `NovelWebview`,
+    /// or let `PixivClient::fetch_novel_text` do both.
+    pub fn request_novel_webview(novel_id: usize) -> PixivRequest {
+        let uri = format!("{}/webview/v2/novel", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param("id", novel_id.to_string())
+            .add_param("viewer_version", NOVEL_VIEWER_VERSION)
+            .finish()
+    }
+
+    /// Used to build a request for a novel series and the first page of its novels.
+    /// Served from `v2`, like novel details.
+    /// Decodes into `NovelSeries`, whose `next_url` leads to the following pages.
+    pub fn request_novel_series(series_id: usize) -> PixivRequest {
+        let uri = format!("{}/v2/novel/series", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param("series_id", series_id.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to search novels. Takes the same arguments as an illustration search,
+    /// and `SearchTarget::Text` or `SearchTarget::Keyword` on top. Decodes into `NovelSearchProxy`.
+    pub fn request_novel_search<T>(params: T) -> PixivRequest
+    where
+        T: Into<IllustrationSearchRequestArg>,
+    {
+        let url = http::Uri::try_from(format!("{}/v1/search/novel", BASE_URL)).unwrap();
+        params
+            .into()
+            .into_iter()
+            .fold(
+                PixivRequest::new(Method::GET, url),
+                |acc, (k, v): (&'static str, String)| acc.add_param(k, v),
+            )
+            .finish()
+    }
+
+    /// Used to build a request for the novel ranking. Decodes into `NovelRanking`.
+    /// Fails if pixiv has no ranking of the mode for the date asked for.
+    pub fn request_novels_ranking<T>(argument: T) -> Result<PixivRequest, RankingDateError>
+    where
+        T: Into<IllustrationRankingRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/novel/ranking", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        Ok(argument
+            .build()?
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
+            .finish())
+    }
+
+    /// Used to build a request for novels recommended to the logged in user.
+    /// Decodes into `RecommendedNovel`.
+    pub fn request_recommended_novel(offset: usize, include_ranking_novels: bool) -> PixivRequest {
+        let uri = format!("{}/v1/novel/recommended", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(OFFSET, offset.to_string())
+            .add_param("include_ranking_novels", include_ranking_novels.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to list a user's novels. Decodes into `UserNovels`.
+    pub fn request_user_novels(user_id: usize, offset: usize) -> PixivRequest {
+        let uri = format!("{}/v1/user/novels", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::GET, uri)
+            .add_param(USER_ID, user_id.to_string())
+            .add_param(OFFSET, offset.to_string())
+            .finish()
+    }
+
+    /// Used to build a request to list the novels a user bookmarked.
+    /// Takes the same arguments as the illustration bookmarks. Decodes into `UserBookmarksNovel`.
+    pub fn request_user_bookmarks_novel<T>(argument: T) -> PixivRequest
+    where
+        T: Into<UserBookmarksIllustrationRequestArg>,
+    {
+        let argument = argument.into();
+        let uri = format!("{}/v1/user/bookmarks/novel", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        argument
+            .build()
+            .iter()
+            .fold(PixivRequest::new(Method::GET, uri), |acc, (key, val)| {
+                acc.add_param(key, String::from(val))
+            })
+            .finish()
+    }
+
+    /// Used to build a request to bookmark a novel.
+    pub fn request_adding_novel_bookmark(novel_id: usize, visibility: Visibility) -> PixivRequest {
+        let uri = format!("{}/v2/novel/bookmark/add", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::POST, uri)
+            .add_form(NOVEL_ID, novel_id.to_string())
+            .add_form(RESTRICT, visibility.as_str())
+            .finish()
+    }
+
+    /// Used to build a request to remove a novel bookmark.
+    pub fn request_delete_novel_bookmark(novel_id: usize) -> PixivRequest {
+        let uri = format!("{}/v1/novel/bookmark/delete", BASE_URL);
+        let bytes = Bytes::from(uri.as_str());
+        let uri = Uri::from_shared(bytes).unwrap();
+        PixivRequest::new(Method::POST, uri)
+            .add_form(NOVEL_ID, novel_id.to_string())
+            .finish()
+    }
 }
@@ -0,0 +1,59 @@
+use crate::pixiv::de::optional_url;
+use crate::pixiv::helper_structs::bookmark_tag::BookmarkTag;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of the logged in user's bookmark tags.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BookmarkTags {
+    bookmark_tags: Vec<BookmarkTag>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// Holds no image URLs.
+impl RewriteImageHost for BookmarkTags {
+    fn rewrite_image_host(&mut self, _host: &str) {}
+}
+
+impl BookmarkTags {
+    pub fn bookmark_tags(&self) -> &[BookmarkTag] {
+        &self.bookmark_tags
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn into_inner(self) -> Vec<BookmarkTag> {
+        self.bookmark_tags
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for BookmarkTags {
+    type Item = BookmarkTag;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.bookmark_tags
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.bookmark_tags
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
@@ -0,0 +1,34 @@
+use crate::pixiv::helper_structs::comment::Comment;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// Response of posting a comment, which wraps the new comment in "comment".
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommentProxy {
+    comment: Comment,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl CommentProxy {
+    pub fn comment(&self) -> &Comment {
+        &self.comment
+    }
+
+    pub fn into_inner(self) -> Comment {
+        self.comment
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl RewriteImageHost for CommentProxy {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.comment.rewrite_image_host(host);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::enums::Visibility;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether the logged in user follows someone, and how.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FollowDetail {
+    is_followed: bool,
+    restrict: Visibility,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl FollowDetail {
+    pub fn is_followed(&self) -> bool {
+        self.is_followed
+    }
+
+    /// Whether the follow is public or private. pixiv sends `public` when not following.
+    pub fn restrict(&self) -> &Visibility {
+        &self.restrict
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
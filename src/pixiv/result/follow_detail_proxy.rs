@@ -0,0 +1,32 @@
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::result::follow_detail::FollowDetail;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FollowDetailProxy {
+    follow_detail: FollowDetail,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// Holds no image URLs.
+impl RewriteImageHost for FollowDetailProxy {
+    fn rewrite_image_host(&mut self, _host: &str) {}
+}
+
+impl FollowDetailProxy {
+    pub fn follow_detail(&self) -> &FollowDetail {
+        &self.follow_detail
+    }
+
+    pub fn into_inner(self) -> FollowDetail {
+        self.follow_detail
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
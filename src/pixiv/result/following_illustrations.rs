@@ -0,0 +1,4 @@
+use crate::pixiv::result::illustration_list::IllustrationList;
+
+/// A page of the newest works of the users the logged in user follows, newest first.
+pub type FollowingIllustrations = IllustrationList;
@@ -1,14 +1,63 @@
+use crate::enums::Visibility;
+use crate::pixiv::schema::ExtraFields;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IllustBookmarkInfoTag {
     is_registered: bool,
     name: String,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IllustBookmarkInfo {
     is_bookmarked: bool,
-    restrict: String,
+    restrict: Visibility,
     tags: Vec<IllustBookmarkInfoTag>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl IllustBookmarkInfoTag {
+    pub fn is_registered(&self) -> bool {
+        self.is_registered
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl IllustBookmarkInfo {
+    pub fn is_bookmarked(&self) -> bool {
+        self.is_bookmarked
+    }
+
+    pub fn restrict(&self) -> &Visibility {
+        &self.restrict
+    }
+
+    pub fn tags(&self) -> &[IllustBookmarkInfoTag] {
+        &self.tags
+    }
+
+    /// Names of the tags the illustration is bookmarked under.
+    pub fn registered_tag_names(&self) -> impl Iterator<Item = &str> {
+        self.tags
+            .iter()
+            .filter(|tag| tag.is_registered)
+            .map(IllustBookmarkInfoTag::name)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
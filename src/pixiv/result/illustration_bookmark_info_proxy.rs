@@ -1,8 +1,32 @@
+use crate::pixiv::image_host::RewriteImageHost;
 use crate::pixiv::result::illustration_bookmark_info::IllustBookmarkInfo;
+use crate::pixiv::schema::ExtraFields;
 
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IllustBookmarkInfoProxy {
     bookmark_detail: IllustBookmarkInfo,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// Holds no image URLs.
+impl RewriteImageHost for IllustBookmarkInfoProxy {
+    fn rewrite_image_host(&mut self, _host: &str) {}
+}
+
+impl IllustBookmarkInfoProxy {
+    pub fn bookmark_detail(&self) -> &IllustBookmarkInfo {
+        &self.bookmark_detail
+    }
+
+    pub fn into_inner(self) -> IllustBookmarkInfo {
+        self.bookmark_detail
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
@@ -1,12 +1,22 @@
+use crate::pixiv::de::optional_url;
 use crate::pixiv::helper_structs::comment::Comment;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 /// IllustrationComment
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IllustrationComment {
     comments: Vec<Comment>,
-    next_url: String,
-    total_comments: u32,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    // Only sent if `include_total_comments` was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    total_comments: Option<u32>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
 }
 
 impl IntoIterator for IllustrationComment {
@@ -18,3 +28,45 @@ impl IntoIterator for IllustrationComment {
         self.comments.into_iter()
     }
 }
+
+impl RewriteImageHost for IllustrationComment {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.comments.rewrite_image_host(host);
+    }
+}
+
+impl IllustrationComment {
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn total_comments(&self) -> Option<u32> {
+        self.total_comments
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for IllustrationComment {
+    type Item = Comment;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.comments
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.comments
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
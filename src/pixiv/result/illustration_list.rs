@@ -0,0 +1,71 @@
+use crate::pixiv::de::optional_url;
+use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of illustrations and where the next one is, the shape most illustration listings share.
+/// Listings are aliases of it, e.g. `IllustrationRanking` or `UserBookmarksIllustration`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IllustrationList {
+    illusts: Vec<Illustration>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for IllustrationList {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illusts.rewrite_image_host(host);
+    }
+}
+
+impl IllustrationList {
+    pub fn illusts(&self) -> &[Illustration] {
+        &self.illusts
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    /// The `max_bookmark_id` the next page starts from, to resume a backup later.
+    /// Only bookmark listings page this way.
+    pub fn next_max_bookmark_id(&self) -> Option<u64> {
+        self.next_url
+            .as_ref()?
+            .query_pairs()
+            .find(|(key, _)| key == "max_bookmark_id")
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
+    pub fn into_inner(self) -> Vec<Illustration> {
+        self.illusts
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for IllustrationList {
+    type Item = Illustration;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.illusts
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.illusts
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
@@ -1,4 +1,6 @@
 use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
 
 use serde::{Deserialize, Serialize};
 
@@ -7,10 +9,27 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IllustrationProxy {
     pub illust: Illustration,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
 }
 
 impl IllustrationProxy {
+    pub fn illust(&self) -> &Illustration {
+        &self.illust
+    }
+
     pub fn into_inner(self) -> Illustration {
         self.illust
     }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl RewriteImageHost for IllustrationProxy {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illust.rewrite_image_host(host);
+    }
 }
@@ -1,18 +1,67 @@
+use crate::pixiv::de::optional_url;
 use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 /// PixivClient hides the actual illustration object behind the value "illust".
 /// This struct exists purely to bypass this indirection...
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IllustrationSearchProxy {
     illusts: Vec<Illustration>,
-    next_url: String,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(default)]
     search_span_limit: u32,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
 }
 
 impl IllustrationSearchProxy {
+    pub fn illusts(&self) -> &[Illustration] {
+        &self.illusts
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn search_span_limit(&self) -> u32 {
+        self.search_span_limit
+    }
+
     pub fn into_inner(self) -> Vec<Illustration> {
         self.illusts
     }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl RewriteImageHost for IllustrationSearchProxy {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illusts.rewrite_image_host(host);
+    }
+}
+
+impl Paginated for IllustrationSearchProxy {
+    type Item = Illustration;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.illusts
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.illusts
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
 }
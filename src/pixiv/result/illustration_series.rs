@@ -0,0 +1,89 @@
+use crate::pixiv::de::optional_url;
+use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::helper_structs::illustration_series_detail::IllustrationSeriesDetail;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of the works in an illustration or manga series, along with the series.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IllustrationSeries {
+    illust_series_detail: IllustrationSeriesDetail,
+    illust_series_first_illust: Illustration,
+    illusts: Vec<Illustration>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for IllustrationSeries {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illust_series_detail.rewrite_image_host(host);
+        self.illust_series_first_illust.rewrite_image_host(host);
+        self.illusts.rewrite_image_host(host);
+    }
+}
+
+impl IllustrationSeries {
+    pub fn detail(&self) -> &IllustrationSeriesDetail {
+        &self.illust_series_detail
+    }
+
+    /// The work the series starts with, sent with every page.
+    pub fn first_illust(&self) -> &Illustration {
+        &self.illust_series_first_illust
+    }
+
+    /// The works on this page, in the order pixiv lists them.
+    pub fn illusts(&self) -> &[Illustration] {
+        &self.illusts
+    }
+
+    /// The works on this page, oldest first.
+    pub fn reading_order(&self) -> Vec<&Illustration> {
+        let mut illusts = self.illusts.iter().collect::<Vec<_>>();
+        illusts.sort_by_key(|illust| *illust.create_date());
+        illusts
+    }
+
+    /// Add the works of the following page, taking over its link to the page after.
+    /// Appending every page in turn gives the whole series, see `PixivClient::fetch_illustration_series`.
+    pub fn append(&mut self, next: IllustrationSeries) {
+        self.illusts.extend(next.illusts);
+        self.next_url = next.next_url;
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn into_inner(self) -> Vec<Illustration> {
+        self.illusts
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for IllustrationSeries {
+    type Item = Illustration;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.illusts
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.illusts
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
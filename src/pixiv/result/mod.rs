@@ -1,9 +1,34 @@
+pub mod bookmark_tags;
+pub mod comment_proxy;
+pub mod follow_detail;
+pub mod follow_detail_proxy;
+pub mod following_illustrations;
 pub mod illustration_bookmark_info;
 pub mod illustration_bookmark_info_proxy;
 pub mod illustration_comment;
+pub mod illustration_list;
 pub mod illustration_proxy;
 pub mod illustration_ranking;
 pub mod illustration_search_proxy;
+pub mod illustration_series;
+pub mod new_illustrations;
+pub mod novel_list;
+pub mod novel_proxy;
+pub mod novel_ranking;
+pub mod novel_search_proxy;
+pub mod novel_series;
+pub mod novel_text;
+pub mod novel_webview;
+pub mod popular_preview;
 pub mod recommended_illustration;
+pub mod recommended_novel;
 pub mod related_illustration_search_proxy;
+pub mod search_autocomplete;
+pub mod spotlight_articles;
 pub mod trending_illustrations;
+pub mod user_bookmarks_illustration;
+pub mod user_bookmarks_novel;
+pub mod user_detail;
+pub mod user_illustrations;
+pub mod user_novels;
+pub mod user_previews;
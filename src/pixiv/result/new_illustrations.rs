@@ -0,0 +1,4 @@
+use crate::pixiv::result::illustration_list::IllustrationList;
+
+/// A page of the newest works on pixiv, newest first.
+pub type NewIllustrations = IllustrationList;
@@ -0,0 +1,34 @@
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// pixiv hides the actual novel object behind the value "novel".
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NovelProxy {
+    novel: Novel,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl NovelProxy {
+    pub fn novel(&self) -> &Novel {
+        &self.novel
+    }
+
+    pub fn into_inner(self) -> Novel {
+        self.novel
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl RewriteImageHost for NovelProxy {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.novel.rewrite_image_host(host);
+    }
+}
@@ -0,0 +1,4 @@
+use crate::pixiv::result::novel_list::NovelList;
+
+/// A page of the novel ranking.
+pub type NovelRanking = NovelList;
@@ -0,0 +1,66 @@
+use crate::pixiv::de::optional_url;
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of novel search results.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NovelSearchProxy {
+    novels: Vec<Novel>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(default)]
+    search_span_limit: u32,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for NovelSearchProxy {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.novels.rewrite_image_host(host);
+    }
+}
+
+impl NovelSearchProxy {
+    pub fn novels(&self) -> &[Novel] {
+        &self.novels
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn search_span_limit(&self) -> u32 {
+        self.search_span_limit
+    }
+
+    pub fn into_inner(self) -> Vec<Novel> {
+        self.novels
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for NovelSearchProxy {
+    type Item = Novel;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.novels
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.novels
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
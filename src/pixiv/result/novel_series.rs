@@ -0,0 +1,90 @@
+use crate::pixiv::de::{empty_object_as_none, optional_url};
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::helper_structs::novel_series_detail::NovelSeriesDetail;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of the novels in a series, along with the series.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NovelSeries {
+    novel_series_detail: NovelSeriesDetail,
+    // pixiv sends `{}` when the novel is not visible to the logged in user.
+    #[serde(default, deserialize_with = "empty_object_as_none")]
+    novel_series_first_novel: Option<Novel>,
+    #[serde(default, deserialize_with = "empty_object_as_none")]
+    novel_series_latest_novel: Option<Novel>,
+    novels: Vec<Novel>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for NovelSeries {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.novel_series_detail.rewrite_image_host(host);
+        self.novel_series_first_novel.rewrite_image_host(host);
+        self.novel_series_latest_novel.rewrite_image_host(host);
+        self.novels.rewrite_image_host(host);
+    }
+}
+
+impl NovelSeries {
+    pub fn detail(&self) -> &NovelSeriesDetail {
+        &self.novel_series_detail
+    }
+
+    pub fn first_novel(&self) -> Option<&Novel> {
+        self.novel_series_first_novel.as_ref()
+    }
+
+    pub fn latest_novel(&self) -> Option<&Novel> {
+        self.novel_series_latest_novel.as_ref()
+    }
+
+    /// The novels on this page, in the order the author arranged the series.
+    pub fn novels(&self) -> &[Novel] {
+        &self.novels
+    }
+
+    /// Add the novels of the following page, taking over its link to the page after.
+    /// Appending every page in turn gives the whole series, see `PixivClient::fetch_novel_series`.
+    pub fn append(&mut self, next: NovelSeries) {
+        self.novels.extend(next.novels);
+        self.next_url = next.next_url;
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn into_inner(self) -> Vec<Novel> {
+        self.novels
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for NovelSeries {
+    type Item = Novel;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.novels
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.novels
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
@@ -0,0 +1,57 @@
+use crate::pixiv::de::empty_object_as_none;
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::novel_markup::NovelDocument;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// The body of a novel in pixiv's markup, with its neighbours in the series.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NovelText {
+    // The reader's bookmark in the novel, `{}` if there is none.
+    novel_marker: serde_json::Value,
+    novel_text: String,
+    #[serde(default, deserialize_with = "empty_object_as_none")]
+    series_next: Option<Novel>,
+    #[serde(default, deserialize_with = "empty_object_as_none")]
+    series_prev: Option<Novel>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for NovelText {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.series_next.rewrite_image_host(host);
+        self.series_prev.rewrite_image_host(host);
+    }
+}
+
+impl NovelText {
+    pub fn novel_marker(&self) -> &serde_json::Value {
+        &self.novel_marker
+    }
+
+    /// The raw markup.
+    pub fn novel_text(&self) -> &str {
+        &self.novel_text
+    }
+
+    /// The markup parsed into pages, chapters, ruby, images and links.
+    pub fn document(&self) -> NovelDocument {
+        NovelDocument::parse(&self.novel_text)
+    }
+
+    pub fn series_next(&self) -> Option<&Novel> {
+        self.series_next.as_ref()
+    }
+
+    pub fn series_prev(&self) -> Option<&Novel> {
+        self.series_prev.as_ref()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
@@ -0,0 +1,320 @@
+use crate::enums::AiType;
+use crate::errors::DecodeError;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::novel_markup::NovelDocument;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::de::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use std::collections::HashMap;
+
+/// A novel as pixiv's novel viewer page embeds it, with its body in pixiv's markup.
+///
+/// `/webview/v2/novel` answers with an HTML page rather than JSON,
+/// read the novel out of it with `NovelWebview::payload` before decoding.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelWebview {
+    id: String,
+    title: String,
+    series_id: Option<String>,
+    series_title: Option<String>,
+    user_id: String,
+    cover_url: String,
+    tags: Vec<String>,
+    caption: String,
+    cdate: String,
+    rating: NovelWebviewRating,
+    text: String,
+    // The reader's bookmark in the novel, `null` if there is none.
+    marker: Value,
+    // Illustrations embedded with `[pixivimage:]`, keyed by `<illust id>` or `<illust id>-<page>`.
+    illusts: Value,
+    // Images uploaded with the novel for `[uploadedimage:]`, keyed by image id.
+    images: HashMap<String, NovelWebviewImage>,
+    series_navigation: Option<NovelWebviewSeriesNavigation>,
+    ai_type: AiType,
+    is_original: bool,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NovelWebviewRating {
+    like: u32,
+    bookmark: u32,
+    view: u32,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// An image uploaded with a novel, in every size pixiv serves it.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelWebviewImage {
+    novel_image_id: String,
+    sl: String,
+    // Keyed by size, e.g. `240mw`, `1200x1200` or `original`.
+    urls: HashMap<String, String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelWebviewSeriesNavigation {
+    next_novel: Option<NovelWebviewNeighbour>,
+    prev_novel: Option<NovelWebviewNeighbour>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// The previous or next novel of a series, as the viewer page links to it.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelWebviewNeighbour {
+    id: u32,
+    viewable: bool,
+    content_order: String,
+    title: String,
+    cover_url: String,
+    viewable_message: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for NovelWebview {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.cover_url.rewrite_image_host(host);
+        self.illusts.rewrite_image_host(host);
+        self.images.rewrite_image_host(host);
+        if let Some(navigation) = &mut self.series_navigation {
+            navigation.next_novel.rewrite_image_host(host);
+            navigation.prev_novel.rewrite_image_host(host);
+        }
+    }
+}
+
+impl RewriteImageHost for NovelWebviewImage {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.urls.rewrite_image_host(host);
+    }
+}
+
+impl RewriteImageHost for NovelWebviewNeighbour {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.cover_url.rewrite_image_host(host);
+    }
+}
+
+impl NovelWebview {
+    /// The novel embedded in a viewer page, as the value following the first `novel:` that holds an object.
+    /// Fails with `DecodeError::Json` if the page has none, e.g. because pixiv changed the page.
+    pub fn payload(html: &str) -> Result<Value, DecodeError> {
+        html.match_indices("novel:")
+            .filter_map(|(start, key)| {
+                let rest = html[start + key.len()..].trim_start();
+                if !rest.starts_with('{') {
+                    return None;
+                }
+                // The object is followed by the rest of the script, only read the object itself.
+                serde_json::Deserializer::from_str(rest)
+                    .into_iter::<Value>()
+                    .next()?
+                    .ok()
+            })
+            .find(Value::is_object)
+            .ok_or_else(|| DecodeError::Json(serde_json::Error::custom("no novel in the page")))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn series_id(&self) -> Option<&str> {
+        self.series_id.as_deref()
+    }
+
+    pub fn series_title(&self) -> Option<&str> {
+        self.series_title.as_deref()
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn cover_url(&self) -> &str {
+        &self.cover_url
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    /// The creation date, as the page shows it.
+    pub fn cdate(&self) -> &str {
+        &self.cdate
+    }
+
+    pub fn rating(&self) -> &NovelWebviewRating {
+        &self.rating
+    }
+
+    /// The raw markup.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The markup parsed into pages, chapters, ruby, images and links.
+    pub fn document(&self) -> NovelDocument {
+        NovelDocument::parse(&self.text)
+    }
+
+    pub fn marker(&self) -> &Value {
+        &self.marker
+    }
+
+    pub fn illusts(&self) -> &Value {
+        &self.illusts
+    }
+
+    /// Images uploaded with the novel, keyed by image id.
+    pub fn images(&self) -> &HashMap<String, NovelWebviewImage> {
+        &self.images
+    }
+
+    /// The original of an image uploaded with the novel, see `Node::UploadedImage`.
+    pub fn uploaded_image_url(&self, image_id: u64) -> Option<&str> {
+        self.images
+            .get(&image_id.to_string())
+            .and_then(NovelWebviewImage::original_url)
+    }
+
+    /// The series navigation, `None` for novels outside a series.
+    pub fn series_navigation(&self) -> Option<&NovelWebviewSeriesNavigation> {
+        self.series_navigation.as_ref()
+    }
+
+    pub fn series_next(&self) -> Option<&NovelWebviewNeighbour> {
+        self.series_navigation.as_ref()?.next_novel.as_ref()
+    }
+
+    pub fn series_prev(&self) -> Option<&NovelWebviewNeighbour> {
+        self.series_navigation.as_ref()?.prev_novel.as_ref()
+    }
+
+    pub fn ai_type(&self) -> AiType {
+        self.ai_type
+    }
+
+    pub fn is_original(&self) -> bool {
+        self.is_original
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl NovelWebviewRating {
+    pub fn like(&self) -> u32 {
+        self.like
+    }
+
+    pub fn bookmark(&self) -> u32 {
+        self.bookmark
+    }
+
+    pub fn view(&self) -> u32 {
+        self.view
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl NovelWebviewImage {
+    pub fn novel_image_id(&self) -> &str {
+        &self.novel_image_id
+    }
+
+    /// The sanity level, as the page sends it.
+    pub fn sl(&self) -> &str {
+        &self.sl
+    }
+
+    /// Every size of the image, keyed by size, e.g. `240mw`, `1200x1200` or `original`.
+    pub fn urls(&self) -> &HashMap<String, String> {
+        &self.urls
+    }
+
+    pub fn original_url(&self) -> Option<&str> {
+        self.urls.get("original").map(String::as_str)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl NovelWebviewSeriesNavigation {
+    pub fn next_novel(&self) -> Option<&NovelWebviewNeighbour> {
+        self.next_novel.as_ref()
+    }
+
+    pub fn prev_novel(&self) -> Option<&NovelWebviewNeighbour> {
+        self.prev_novel.as_ref()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl NovelWebviewNeighbour {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn is_viewable(&self) -> bool {
+        self.viewable
+    }
+
+    /// The novel's position in the series, as pixiv numbers it.
+    pub fn content_order(&self) -> &str {
+        &self.content_order
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn cover_url(&self) -> &str {
+        &self.cover_url
+    }
+
+    /// Why the novel cannot be read, if it cannot.
+    pub fn viewable_message(&self) -> Option<&str> {
+        self.viewable_message.as_deref()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
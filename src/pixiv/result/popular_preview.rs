@@ -0,0 +1,35 @@
+use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// The handful of most popular results of a search, which pixiv shows to every account.
+/// There is only ever one page.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PopularPreview {
+    illusts: Vec<Illustration>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl PopularPreview {
+    pub fn illusts(&self) -> &[Illustration] {
+        &self.illusts
+    }
+
+    pub fn into_inner(self) -> Vec<Illustration> {
+        self.illusts
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl RewriteImageHost for PopularPreview {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illusts.rewrite_image_host(host);
+    }
+}
@@ -1,14 +1,77 @@
+use crate::pixiv::de::{null_as_default, optional_url};
 use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RecommendedIllustration {
     contest_exists: bool,
     illusts: Vec<Illustration>,
-    next_url: String,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
     // TODO: Figure out the correct struct for this.
     privacy_policy: serde_json::Value,
-    // TODO: Figure out the correct struct for this.
-    ranking_illusts: Vec<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    ranking_illusts: Vec<Illustration>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for RecommendedIllustration {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.illusts.rewrite_image_host(host);
+        self.ranking_illusts.rewrite_image_host(host);
+    }
+}
+
+impl RecommendedIllustration {
+    pub fn contest_exists(&self) -> bool {
+        self.contest_exists
+    }
+
+    pub fn illusts(&self) -> &[Illustration] {
+        &self.illusts
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn privacy_policy(&self) -> &serde_json::Value {
+        &self.privacy_policy
+    }
+
+    pub fn ranking_illusts(&self) -> &[Illustration] {
+        &self.ranking_illusts
+    }
+
+    pub fn into_inner(self) -> Vec<Illustration> {
+        self.illusts
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for RecommendedIllustration {
+    type Item = Illustration;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.illusts
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.illusts
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
 }
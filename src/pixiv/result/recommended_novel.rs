@@ -0,0 +1,70 @@
+use crate::pixiv::de::{null_as_default, optional_url};
+use crate::pixiv::helper_structs::novel::Novel;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of novels recommended to the logged in user.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecommendedNovel {
+    novels: Vec<Novel>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    privacy_policy: serde_json::Value,
+    #[serde(default, deserialize_with = "null_as_default")]
+    ranking_novels: Vec<Novel>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for RecommendedNovel {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.novels.rewrite_image_host(host);
+        self.ranking_novels.rewrite_image_host(host);
+    }
+}
+
+impl RecommendedNovel {
+    pub fn novels(&self) -> &[Novel] {
+        &self.novels
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    /// Novels from today's ranking, only on the first page when asked for.
+    pub fn ranking_novels(&self) -> &[Novel] {
+        &self.ranking_novels
+    }
+
+    pub fn into_inner(self) -> Vec<Novel> {
+        self.novels
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for RecommendedNovel {
+    type Item = Novel;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.novels
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.novels
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::pixiv::helper_structs::tag::Tag;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+
+/// Tags suggested for a partially typed search word, with their translations.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchAutocomplete {
+    tags: Vec<Tag>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+/// Holds no image URLs.
+impl RewriteImageHost for SearchAutocomplete {
+    fn rewrite_image_host(&mut self, _host: &str) {}
+}
+
+impl SearchAutocomplete {
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn tag_names(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(Tag::name)
+    }
+
+    pub fn into_inner(self) -> Vec<Tag> {
+        self.tags
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
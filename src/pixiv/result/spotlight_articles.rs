@@ -0,0 +1,60 @@
+use crate::pixiv::de::optional_url;
+use crate::pixiv::helper_structs::spotlight_article::SpotlightArticle;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of Pixivision articles, newest first.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SpotlightArticles {
+    spotlight_articles: Vec<SpotlightArticle>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for SpotlightArticles {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.spotlight_articles.rewrite_image_host(host);
+    }
+}
+
+impl SpotlightArticles {
+    pub fn spotlight_articles(&self) -> &[SpotlightArticle] {
+        &self.spotlight_articles
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn into_inner(self) -> Vec<SpotlightArticle> {
+        self.spotlight_articles
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for SpotlightArticles {
+    type Item = SpotlightArticle;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.spotlight_articles
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.spotlight_articles
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
@@ -1,8 +1,33 @@
 use crate::pixiv::helper_structs::illustration_tag::IllustrationTag;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
 use serde::{Deserialize, Serialize};
 
 /// TrendingIllustration
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TrendingIllustrations {
     trend_tags: Vec<IllustrationTag>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for TrendingIllustrations {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.trend_tags.rewrite_image_host(host);
+    }
+}
+
+impl TrendingIllustrations {
+    pub fn trend_tags(&self) -> &[IllustrationTag] {
+        &self.trend_tags
+    }
+
+    pub fn into_inner(self) -> Vec<IllustrationTag> {
+        self.trend_tags
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
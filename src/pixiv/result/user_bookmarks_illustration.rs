@@ -0,0 +1,5 @@
+use crate::pixiv::result::illustration_list::IllustrationList;
+
+/// A page of the illustrations a user bookmarked.
+/// `next_max_bookmark_id` tells where the next page starts.
+pub type UserBookmarksIllustration = IllustrationList;
@@ -0,0 +1,5 @@
+use crate::pixiv::result::novel_list::NovelList;
+
+/// A page of the novels a user bookmarked.
+/// `next_max_bookmark_id` tells where the next page starts.
+pub type UserBookmarksNovel = NovelList;
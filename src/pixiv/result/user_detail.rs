@@ -0,0 +1,50 @@
+use crate::pixiv::helper_structs::profile_publicity::ProfilePublicity;
+use crate::pixiv::helper_structs::user_profile::UserProfile;
+use crate::pixiv::helper_structs::workspace::Workspace;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
+use crate::pixiv::user::User;
+
+use serde::{Deserialize, Serialize};
+
+/// Response of the user detail endpoint: the user with their full profile.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserDetail {
+    profile: UserProfile,
+    profile_publicity: ProfilePublicity,
+    user: User,
+    workspace: Workspace,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for UserDetail {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.profile.rewrite_image_host(host);
+        self.user.rewrite_image_host(host);
+        self.workspace.rewrite_image_host(host);
+    }
+}
+
+impl UserDetail {
+    pub fn profile(&self) -> &UserProfile {
+        &self.profile
+    }
+
+    pub fn profile_publicity(&self) -> &ProfilePublicity {
+        &self.profile_publicity
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn workspace(&self) -> &Workspace {
+        &self.workspace
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
@@ -0,0 +1,69 @@
+use crate::pixiv::helper_structs::illustration::Illustration;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::result::illustration_list::IllustrationList;
+use crate::pixiv::schema::ExtraFields;
+use crate::pixiv::user::User;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of a user's illustrations or manga, along with the user.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserIllustrations {
+    user: User,
+    #[serde(flatten)]
+    list: IllustrationList,
+}
+
+impl RewriteImageHost for UserIllustrations {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.user.rewrite_image_host(host);
+        self.list.rewrite_image_host(host);
+    }
+}
+
+impl UserIllustrations {
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn illusts(&self) -> &[Illustration] {
+        self.list.illusts()
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.list.next_url()
+    }
+
+    /// The page without the user.
+    pub fn list(&self) -> &IllustrationList {
+        &self.list
+    }
+
+    pub fn into_inner(self) -> Vec<Illustration> {
+        self.list.into_inner()
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        self.list.extra()
+    }
+}
+
+impl Paginated for UserIllustrations {
+    type Item = Illustration;
+
+    fn items(&self) -> &[Self::Item] {
+        self.list.items()
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        self.list.items_mut()
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        Paginated::next_url(&self.list)
+    }
+}
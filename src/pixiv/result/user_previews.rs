@@ -0,0 +1,60 @@
+use crate::pixiv::de::optional_url;
+use crate::pixiv::helper_structs::user_preview::UserPreview;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::pagination::Paginated;
+use crate::pixiv::schema::ExtraFields;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A page of users, as returned by the following and other user listings.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserPreviews {
+    user_previews: Vec<UserPreview>,
+    #[serde(default, deserialize_with = "optional_url")]
+    next_url: Option<Url>,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for UserPreviews {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.user_previews.rewrite_image_host(host);
+    }
+}
+
+impl UserPreviews {
+    pub fn user_previews(&self) -> &[UserPreview] {
+        &self.user_previews
+    }
+
+    /// Where the next page is, or `None` on the last page.
+    pub fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+
+    pub fn into_inner(self) -> Vec<UserPreview> {
+        self.user_previews
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
+}
+
+impl Paginated for UserPreviews {
+    type Item = UserPreview;
+
+    fn items(&self) -> &[Self::Item] {
+        &self.user_previews
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.user_previews
+    }
+
+    fn next_url(&self) -> Option<&Url> {
+        self.next_url.as_ref()
+    }
+}
@@ -0,0 +1,141 @@
+use crate::errors::DecodeError;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// Fields pixiv sent that the model does not know about yet.
+/// Models collect these instead of dropping them; they are not serialized back.
+pub type ExtraFields = HashMap<String, Value>;
+
+/// How `decode` reacts to a response that does not match the model exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaMode {
+    /// Decode whatever fits, silently keeping unknown fields in the models' extra maps.
+    Lenient,
+    /// Decode, but log a warning for every unknown or missing field.
+    Warn,
+    /// Refuse responses with unknown fields, or without fields pixiv always sends.
+    Strict,
+}
+
+/// `Strict` if the crate is built with the `strict-schema` feature, `Lenient` otherwise.
+impl Default for SchemaMode {
+    fn default() -> Self {
+        if cfg!(feature = "strict-schema") {
+            SchemaMode::Strict
+        } else {
+            SchemaMode::Lenient
+        }
+    }
+}
+
+/// Differences between a JSON response and the model it was decoded into.
+/// Paths look like `illusts[].tags[].translated_name`, array indices are collapsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaReport {
+    unknown: BTreeSet<String>,
+    missing: BTreeSet<String>,
+}
+
+impl SchemaReport {
+    /// Compare the response with the model re-serialized.
+    /// Keys only in the response are unknown to the model, keys only in the model were not sent.
+    ///
+    /// Models re-serialize the fields pixiv always sends, `null` or not, so a missing one is drift.
+    /// Fields only some endpoints send are left out when they were not sent (see `de::sent`),
+    /// which keeps them from being reported.
+    pub fn compare(response: &Value, model: &Value) -> SchemaReport {
+        let mut report = SchemaReport::default();
+        report.walk(response, model, String::new());
+        report
+    }
+
+    fn walk(&mut self, response: &Value, model: &Value, path: String) {
+        let join = |key: &str| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", path, key)
+            }
+        };
+        match (response, model) {
+            (Value::Object(response), Value::Object(model)) => {
+                for (key, value) in response {
+                    match model.get(key) {
+                        Some(model) => self.walk(value, model, join(key)),
+                        None => {
+                            self.unknown.insert(join(key));
+                        }
+                    }
+                }
+                for key in model.keys().filter(|key| !response.contains_key(*key)) {
+                    self.missing.insert(join(key));
+                }
+            }
+            (Value::Array(response), Value::Array(model)) => {
+                for (response, model) in response.iter().zip(model) {
+                    self.walk(response, model, format!("{}[]", path));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    pub fn unknown(&self) -> impl Iterator<Item = &str> {
+        self.unknown.iter().map(String::as_str)
+    }
+
+    pub fn missing(&self) -> impl Iterator<Item = &str> {
+        self.missing.iter().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unknown.is_empty() && self.missing.is_empty()
+    }
+}
+
+impl fmt::Display for SchemaReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unknown fields: [{}], missing fields: [{}]",
+            self.unknown().collect::<Vec<_>>().join(", "),
+            self.missing().collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+/// Decode a JSON response into a model, checking it for schema drift according to `mode`.
+pub fn decode<T>(response: Value, mode: SchemaMode) -> Result<T, DecodeError>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let model: T = serde_json::from_value(response.clone()).map_err(DecodeError::Json)?;
+    if mode == SchemaMode::Lenient {
+        return Ok(model);
+    }
+
+    let report = SchemaReport::compare(
+        &response,
+        &serde_json::to_value(&model).map_err(DecodeError::Json)?,
+    );
+    if report.is_empty() {
+        return Ok(model);
+    }
+
+    match mode {
+        SchemaMode::Strict => Err(DecodeError::SchemaDrift(report)),
+        _ => {
+            warn!(
+                "Response for {} drifted from the model: {}",
+                std::any::type_name::<T>(),
+                report
+            );
+            Ok(model)
+        }
+    }
+}
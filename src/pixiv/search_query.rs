@@ -0,0 +1,388 @@
+use crate::errors::SearchQueryError;
+
+use std::fmt;
+use std::str::FromStr;
+
+const USERS_IRI_SUFFIX: &str = "users入り";
+
+/// One term of a search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    /// A tag or keyword that must match. Written bare, or quoted if it could not be read back bare.
+    Tag(String),
+    /// Words that must appear together, in order. Written `"a b"`.
+    Phrase(String),
+    /// A tag or phrase that must not match. Written `-tag`.
+    Exclude(String),
+    /// At least one of the terms must match. Written `(a OR b)`.
+    Or(Vec<Term>),
+    /// One of pixiv's popularity tags, like `1000users入り`.
+    UsersIri(u32),
+}
+
+impl Term {
+    /// Read a bare word, recognizing popularity tags.
+    fn from_word(word: String) -> Term {
+        match parse_users_iri(&word) {
+            Some(count) => Term::UsersIri(count),
+            None => Term::Tag(word),
+        }
+    }
+
+    /// A tag that cannot be written bare becomes a phrase, so it reads back the same.
+    fn from_tag(tag: String) -> Term {
+        if is_bare(&tag) {
+            Term::from_word(tag)
+        } else {
+            Term::Phrase(tag)
+        }
+    }
+
+    /// The term as it reads back once written, or `None` if it would read back as nothing:
+    /// an empty exclusion or `OR` group. Groups are flattened and a group of one is its term.
+    fn normalized(self) -> Option<Term> {
+        match self {
+            Term::Tag(tag) => Some(Term::from_tag(tag)),
+            Term::Exclude(text) if text.is_empty() => None,
+            Term::Or(terms) => terms
+                .into_iter()
+                .filter_map(Term::normalized)
+                .reduce(Term::or),
+            term => Some(term),
+        }
+    }
+
+    /// Join two terms into one `OR` group, flattening groups on either side.
+    fn or(left: Term, right: Term) -> Term {
+        let mut terms = match left {
+            Term::Or(terms) => terms,
+            term => vec![term],
+        };
+        match right {
+            Term::Or(right) => terms.extend(right),
+            term => terms.push(term),
+        }
+        Term::Or(terms)
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Term::Tag(tag) if is_bare(tag) => f.write_str(tag),
+            Term::Tag(text) | Term::Phrase(text) => write_quoted(f, text),
+            Term::Exclude(text) => {
+                f.write_str("-")?;
+                if is_bare(text) {
+                    f.write_str(text)
+                } else {
+                    write_quoted(f, text)
+                }
+            }
+            Term::Or(terms) => {
+                f.write_str("(")?;
+                for (index, term) in terms.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" OR ")?;
+                    }
+                    write!(f, "{}", term)?;
+                }
+                f.write_str(")")
+            }
+            Term::UsersIri(count) => write!(f, "{}{}", count, USERS_IRI_SUFFIX),
+        }
+    }
+}
+
+/// A search query in pixiv's syntax, built term by term or parsed from a string.
+/// Terms are separated by spaces and must all match.
+///
+/// ```
+/// use pixieve_rs::pixiv::search_query::SearchQuery;
+///
+/// let query = SearchQuery::new()
+///     .tag("風景")
+///     .any_of(vec!["夏", "海"])
+///     .exclude("R-18")
+///     .users_iri(1000);
+/// assert_eq!(query.to_string(), "風景 (夏 OR 海) -R-18 1000users入り");
+/// assert_eq!(query.to_string().parse(), Ok(query));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<Term>,
+}
+
+impl SearchQuery {
+    pub fn new() -> Self {
+        SearchQuery::default()
+    }
+
+    /// Require a tag or keyword.
+    pub fn tag<T>(mut self, tag: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.terms.push(Term::from_tag(tag.into()));
+        self
+    }
+
+    /// Require words to appear together, in order.
+    pub fn phrase<T>(mut self, phrase: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.terms.push(Term::Phrase(phrase.into()));
+        self
+    }
+
+    /// Leave out works with this tag or phrase. An empty one adds nothing.
+    pub fn exclude<T>(self, tag: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.term(Term::Exclude(tag.into()))
+    }
+
+    /// Require at least one of the tags. A single tag is required as is, none adds nothing.
+    pub fn any_of<I, T>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let term = tags.into_iter().map(|tag| Term::from_tag(tag.into())).fold(
+            None,
+            |acc, term| match acc {
+                None => Some(term),
+                Some(acc) => Some(Term::or(acc, term)),
+            },
+        );
+        self.terms.extend(term);
+        self
+    }
+
+    /// Require one of pixiv's popularity tags, e.g. `1000` for `1000users入り`.
+    pub fn users_iri(mut self, count: u32) -> Self {
+        self.terms.push(Term::UsersIri(count));
+        self
+    }
+
+    /// Add a term as it would be read back, see `Term`.
+    /// Empty exclusions and `OR` groups add nothing, and a group of one adds its term.
+    pub fn term(mut self, term: Term) -> Self {
+        self.terms.extend(term.normalized());
+        self
+    }
+
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, term) in self.terms.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", term)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a query as typed on the website. Full-width spaces separate terms too.
+impl FromStr for SearchQuery {
+    type Err = SearchQueryError;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            length: query.len(),
+            chars: query.char_indices().collect(),
+            index: 0,
+        };
+        Ok(SearchQuery {
+            terms: parser.parse_sequence(None)?,
+        })
+    }
+}
+
+impl From<SearchQuery> for String {
+    fn from(query: SearchQuery) -> Self {
+        query.to_string()
+    }
+}
+
+/// Whether `text` reads back as the same single tag when written without quotes.
+fn is_bare(text: &str) -> bool {
+    !text.is_empty()
+        && !text.starts_with('-')
+        && text != "OR"
+        && !text
+            .chars()
+            .any(|c| c.is_whitespace() || c == '"' || c == '(' || c == ')')
+}
+
+fn write_quoted(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in text.chars() {
+        if c == '"' || c == '\\' {
+            f.write_str("\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    f.write_str("\"")
+}
+
+fn parse_users_iri(word: &str) -> Option<u32> {
+    let count = word.strip_suffix(USERS_IRI_SUFFIX)?;
+    if count.is_empty() || !count.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    count.parse().ok()
+}
+
+struct Parser {
+    length: usize,
+    chars: Vec<(usize, char)>,
+    index: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).map(|&(_, c)| c)
+    }
+
+    fn position(&self) -> usize {
+        self.chars
+            .get(self.index)
+            .map(|&(position, _)| position)
+            .unwrap_or(self.length)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += 1;
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.index += 1;
+        }
+    }
+
+    /// Parse terms up to the end of the query, or up to the `)` closing the group opened at `open`.
+    /// `OR` binds tighter than the space, so `a b OR c` requires `a` and one of `b` or `c`.
+    fn parse_sequence(&mut self, open: Option<usize>) -> Result<Vec<Term>, SearchQueryError> {
+        let mut terms: Vec<Term> = Vec::new();
+        let mut pending_or: Option<usize> = None;
+        // Where the last group of several terms started, if it is the last thing parsed.
+        let mut last_group: Option<usize> = None;
+
+        loop {
+            self.skip_whitespace();
+            let position = self.position();
+            let atom = match self.peek() {
+                None => match open {
+                    Some(open) => return Err(SearchQueryError::UnclosedParen { position: open }),
+                    None => break,
+                },
+                Some(')') => {
+                    if open.is_none() {
+                        return Err(SearchQueryError::UnexpectedParen { position });
+                    }
+                    self.bump();
+                    break;
+                }
+                Some('(') => {
+                    self.bump();
+                    self.parse_sequence(Some(position))?
+                }
+                Some('"') => vec![Term::Phrase(self.parse_quoted()?)],
+                Some('-') => {
+                    self.bump();
+                    let text = match self.peek() {
+                        Some('"') => self.parse_quoted()?,
+                        _ => self.parse_bare(),
+                    };
+                    if text.is_empty() {
+                        return Err(SearchQueryError::EmptyExclusion { position });
+                    }
+                    vec![Term::Exclude(text)]
+                }
+                Some(_) => {
+                    let word = self.parse_bare();
+                    if word == "OR" {
+                        if terms.is_empty() || pending_or.is_some() {
+                            return Err(SearchQueryError::DanglingOr { position });
+                        }
+                        if let Some(group) = last_group {
+                            return Err(SearchQueryError::GroupInOr { position: group });
+                        }
+                        pending_or = Some(position);
+                        continue;
+                    }
+                    vec![Term::from_word(word)]
+                }
+            };
+
+            match pending_or.take() {
+                Some(or) => {
+                    let mut atom = atom.into_iter();
+                    let right = match (atom.next(), atom.next()) {
+                        (Some(right), None) => right,
+                        (None, _) => return Err(SearchQueryError::DanglingOr { position: or }),
+                        (Some(_), Some(_)) => return Err(SearchQueryError::GroupInOr { position }),
+                    };
+                    let left = terms.pop().expect("OR follows a term");
+                    terms.push(Term::or(left, right));
+                    last_group = None;
+                }
+                None => {
+                    last_group = if atom.len() > 1 { Some(position) } else { None };
+                    terms.extend(atom);
+                }
+            }
+        }
+
+        match pending_or {
+            Some(position) => Err(SearchQueryError::DanglingOr { position }),
+            None => Ok(terms),
+        }
+    }
+
+    /// Parse a `"`-quoted string, where `\"` and `\\` stand for `"` and `\`.
+    fn parse_quoted(&mut self) -> Result<String, SearchQueryError> {
+        let start = self.position();
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(SearchQueryError::UnclosedQuote { position: start }),
+                Some('"') => return Ok(text),
+                Some('\\') => match self.bump() {
+                    Some(c) => text.push(c),
+                    None => return Err(SearchQueryError::UnclosedQuote { position: start }),
+                },
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn parse_bare(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || c == '"' || c == '(' || c == ')' {
+                break;
+            }
+            word.push(c);
+            self.index += 1;
+        }
+        word
+    }
+}
@@ -0,0 +1,88 @@
+//! Reading Pixivision articles, which are only published as web pages.
+
+use url::Url;
+
+/// The ids of the illustrations a Pixivision article features, in the order they appear, each once.
+/// Only links inside the `<article>` element count, so works linked from around it are left out.
+/// Pages without one are read whole.
+pub fn featured_illust_ids(html: &str) -> Vec<u32> {
+    let mut ids = Vec::new();
+    let ids_found = attribute_values(article_body(html), "href")
+        .filter_map(|href| illust_id(&href.replace("&amp;", "&").replace("&#38;", "&")));
+    for id in ids_found {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// The part of the page from the first `<article` to the last `</article>`.
+fn article_body(html: &str) -> &str {
+    // ASCII lowercasing keeps byte offsets, so they index `html` too.
+    let lower = html.to_ascii_lowercase();
+    match lower.find("<article") {
+        Some(start) => match lower.rfind("</article>") {
+            Some(end) if end > start => &html[start..end],
+            _ => &html[start..],
+        },
+        None => html,
+    }
+}
+
+/// The values of every `name` attribute, quoted or not.
+fn attribute_values<'a>(html: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> {
+    let lower = html.to_ascii_lowercase();
+    let mut position = 0;
+    std::iter::from_fn(move || loop {
+        let found = position + lower[position..].find(name)?;
+        position = found + name.len();
+        let preceded_by_space = lower[..found]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_whitespace);
+        if !preceded_by_space {
+            continue;
+        }
+        let rest = html[position..].trim_start();
+        let rest = match rest.strip_prefix('=') {
+            Some(rest) => rest.trim_start(),
+            None => continue,
+        };
+        let value = match rest.chars().next() {
+            Some(quote) if quote == '"' || quote == '\'' => {
+                let value = &rest[1..];
+                match value.find(quote) {
+                    Some(end) => &value[..end],
+                    None => continue,
+                }
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(rest.len());
+                &rest[..end]
+            }
+        };
+        return Some(value);
+    })
+}
+
+/// The illustration a link on pixiv.net points at, as `/artworks/id`, `/en/artworks/id`
+/// or the older `/member_illust.php?illust_id=id`.
+fn illust_id(href: &str) -> Option<u32> {
+    let url = Url::parse(href).ok()?;
+    let host = url.host_str()?;
+    if host != "pixiv.net" && host != "www.pixiv.net" {
+        return None;
+    }
+    let segments = url.path_segments()?.collect::<Vec<_>>();
+    match segments.as_slice() {
+        ["artworks", id] | [_, "artworks", id] => id.parse().ok(),
+        ["member_illust.php"] => url
+            .query_pairs()
+            .find(|(key, _)| key == "illust_id")
+            .and_then(|(_, id)| id.parse().ok()),
+        _ => None,
+    }
+}
@@ -1,13 +1,88 @@
+use crate::constants::WEB_URL;
+use crate::pixiv::de::{null_as_default, sent};
 use crate::pixiv::helper_structs::image_url::ImageUrl;
+use crate::pixiv::image_host::RewriteImageHost;
+use crate::pixiv::schema::ExtraFields;
 
 use serde::{Deserialize, Serialize};
 
 /// The user who worked on the illustration (the artist).
 #[derive(Deserialize, Serialize, Debug)]
 pub struct User {
+    #[serde(default, deserialize_with = "null_as_default")]
     account: String,
+    // Only sent by the user detail endpoint.
+    #[serde(
+        default,
+        deserialize_with = "sent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    comment: Option<Option<String>>,
     id: u32,
-    is_followed: Option<bool>,
+    // Only sent by the user detail endpoint.
+    #[serde(
+        default,
+        deserialize_with = "sent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    is_access_blocking_user: Option<Option<bool>>,
+    // Not sent for comment authors.
+    #[serde(
+        default,
+        deserialize_with = "sent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    is_followed: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "null_as_default")]
     name: String,
     profile_image_urls: ImageUrl,
+    #[serde(flatten, skip_serializing)]
+    extra: ExtraFields,
+}
+
+impl RewriteImageHost for User {
+    fn rewrite_image_host(&mut self, host: &str) {
+        self.profile_image_urls.rewrite_image_host(host);
+    }
+}
+
+impl User {
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    /// The self-introduction on the user's profile.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_ref().and_then(Option::as_deref)
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn is_access_blocking_user(&self) -> Option<bool> {
+        self.is_access_blocking_user.flatten()
+    }
+
+    pub fn is_followed(&self) -> Option<bool> {
+        self.is_followed.flatten()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn profile_image_urls(&self) -> &ImageUrl {
+        &self.profile_image_urls
+    }
+
+    /// Link to the user's profile on the pixiv website.
+    pub fn web_url(&self) -> String {
+        format!("{}/users/{}", WEB_URL, self.id)
+    }
+
+    /// Fields pixiv sent that this struct does not know about.
+    pub fn extra(&self) -> &ExtraFields {
+        &self.extra
+    }
 }
@@ -0,0 +1,146 @@
+use pixieve_rs::pixiv::arg::illustration_comment_add_request_arg::IllustrationCommentAddRequestArg;
+use pixieve_rs::pixiv::image_host::RewriteImageHost;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::comment_proxy::CommentProxy;
+use pixieve_rs::pixiv::result::illustration_comment::IllustrationComment;
+
+use http::Method;
+
+mod common;
+
+use common::strict_fixture;
+
+fn comments(fixture: &str) -> IllustrationComment {
+    strict_fixture(fixture)
+}
+
+#[test]
+fn test_v1_comments_are_strict() {
+    let page = comments(include_str!("fixtures/illust_comments.json"));
+
+    assert!(page
+        .comments()
+        .iter()
+        .all(|comment| comment.parent_comment().is_none()
+            && comment.has_replies().is_none()
+            && comment.stamp().is_none()));
+}
+
+#[test]
+fn test_v2_comments() {
+    let page = comments(include_str!("fixtures/illust_comments_v2.json"));
+    let (text, stamp) = (&page.comments()[0], &page.comments()[1]);
+
+    assert_eq!(page.total_comments(), Some(3));
+    assert_eq!(text.has_replies(), Some(true));
+    assert!(text.stamp().is_none());
+    assert!(text.parent_comment().is_none());
+    assert_eq!(stamp.has_replies(), Some(false));
+    assert_eq!(stamp.comment(), "");
+    assert_eq!(stamp.stamp().map(|stamp| stamp.stamp_id()), Some(301));
+    assert!(stamp
+        .stamp()
+        .unwrap()
+        .stamp_url()
+        .starts_with("https://s.pximg.net/common/images/stamp/"));
+}
+
+#[test]
+fn test_comment_replies() {
+    let mut page = comments(include_str!("fixtures/comment_replies.json"));
+
+    assert_eq!(page.items().len(), 1);
+    assert_eq!(page.comments()[0].comment(), "ありがとうございます！");
+    let request = PixivRequestBuilder::request_next_page(Paginated::next_url(&page).unwrap());
+    assert_eq!(request.url.path(), "/v2/illust/comment/replies");
+    assert_eq!(request.url.query(), Some("comment_id=94317621&offset=30"));
+
+    page.rewrite_image_host("https://i.pixiv.re");
+    assert!(page.comments()[0]
+        .user()
+        .profile_image_urls()
+        .medium
+        .as_ref()
+        .unwrap()
+        .starts_with("https://s.pximg.net/"));
+}
+
+#[test]
+fn test_added_reply() {
+    let mut comment =
+        strict_fixture::<CommentProxy>(include_str!("fixtures/comment_add.json")).into_inner();
+
+    assert_eq!(comment.id(), 94330000);
+    let parent = comment.parent_comment().expect("Fixture is a reply.");
+    assert_eq!(parent.id(), 94320000);
+    assert_eq!(parent.user().name(), "another");
+
+    comment.rewrite_image_host("https://i.pixiv.re");
+    assert_eq!(
+        comment.user().profile_image_urls().medium.as_deref(),
+        Some("https://i.pixiv.re/user-profile/img/2017/01/01/00/00/00/11853424_5d2b1e2b8e1d5b3a3e6c2e4f5a6b7c8d_50.jpg")
+    );
+}
+
+#[test]
+fn test_request_comments() {
+    let request = PixivRequestBuilder::request_illustration_comments_v2(75523989, 30);
+    assert_eq!(request.url.path(), "/v2/illust/comments");
+    assert_eq!(
+        request.params.get("illust_id").map(String::as_str),
+        Some("75523989")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("30"));
+
+    let request = PixivRequestBuilder::request_illustration_comment_replies(94317621);
+    assert_eq!(request.url.path(), "/v2/illust/comment/replies");
+    assert_eq!(
+        request.params.get("comment_id").map(String::as_str),
+        Some("94317621")
+    );
+}
+
+#[test]
+fn test_request_adding_comment() {
+    let request = PixivRequestBuilder::request_adding_illustration_comment(
+        IllustrationCommentAddRequestArg::new(75523989, "こちらこそ")
+            .set_parent_comment_id(94320000),
+    );
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.url.path(), "/v1/illust/comment/add");
+    assert_eq!(
+        request.form.get("illust_id").map(String::as_str),
+        Some("75523989")
+    );
+    assert_eq!(
+        request.form.get("comment").map(String::as_str),
+        Some("こちらこそ")
+    );
+    assert_eq!(
+        request.form.get("parent_comment_id").map(String::as_str),
+        Some("94320000")
+    );
+    assert!(!request.form.contains_key("stamp_id"));
+
+    let request = PixivRequestBuilder::request_adding_illustration_comment(
+        IllustrationCommentAddRequestArg::stamp(75523989, 301),
+    );
+    assert_eq!(
+        request.form.get("stamp_id").map(String::as_str),
+        Some("301")
+    );
+    assert!(!request.form.contains_key("comment"));
+    assert!(!request.form.contains_key("parent_comment_id"));
+}
+
+#[test]
+fn test_request_delete_comment() {
+    let request = PixivRequestBuilder::request_delete_illustration_comment(94330000);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.url.path(), "/v1/illust/comment/delete");
+    assert_eq!(
+        request.form.get("comment_id").map(String::as_str),
+        Some("94330000")
+    );
+}
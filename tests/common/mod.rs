@@ -0,0 +1,13 @@
+use pixieve_rs::pixiv::schema::{decode, SchemaMode};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Decode a fixture in strict mode, so it has to match the model exactly.
+pub fn strict_fixture<T>(json: &str) -> T
+where
+    T: DeserializeOwned + Serialize,
+{
+    let value = serde_json::from_str(json).unwrap();
+    decode(value, SchemaMode::Strict).expect("Fixture should match the model.")
+}
@@ -0,0 +1,153 @@
+use pixieve_rs::enums::{AiType, SanityLevel, XRestrict};
+use pixieve_rs::pixiv::content_filter::{ContentFilter, FilterReport};
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::result::illustration_ranking::IllustrationRanking;
+use pixieve_rs::pixiv::result::novel_ranking::NovelRanking;
+
+fn ranking() -> IllustrationRanking {
+    serde_json::from_str(include_str!("fixtures/ranking_mixed.json"))
+        .expect("Failed to parse as json.")
+}
+
+fn ids(ranking: &IllustrationRanking) -> Vec<u32> {
+    ranking.items().iter().map(|illust| illust.id()).collect()
+}
+
+#[test]
+fn test_typed_restriction_fields() {
+    let ranking = ranking();
+    let illusts = ranking.items();
+
+    assert_eq!(illusts[0].illust_ai_type(), AiType::NotAiGenerated);
+    assert_eq!(illusts[0].sanity_level(), SanityLevel::Safe);
+    assert_eq!(illusts[1].x_restrict(), XRestrict::R18);
+    assert_eq!(illusts[1].sanity_level(), SanityLevel::Sensitive);
+    assert!(illusts[2].is_ai_generated());
+    assert!(illusts[3].is_r18g());
+    assert!(SanityLevel::Suggestive < SanityLevel::Sensitive);
+    assert_eq!(
+        serde_json::from_str::<AiType>("7").unwrap(),
+        AiType::Unknown(7)
+    );
+}
+
+#[test]
+fn test_default_filter() {
+    let mut ranking = ranking();
+    let report = ContentFilter::default().apply(&mut ranking);
+
+    assert_eq!(
+        report,
+        FilterReport {
+            kept: 1,
+            dropped: 3
+        }
+    );
+    assert_eq!(ids(&ranking), vec![75523989]);
+    assert!(ranking.next_url().is_some());
+}
+
+#[test]
+fn test_allow_all_with_mutes() {
+    let mut ranking = ranking();
+    let report = ContentFilter::allow_all()
+        .mute_user(2200001)
+        .mute_tag("ホラー")
+        .apply(&mut ranking);
+
+    assert_eq!(report.dropped, 2);
+    assert_eq!(ids(&ranking), vec![75523989, 80171413]);
+}
+
+#[test]
+fn test_max_sanity_level() {
+    let mut ranking = ranking();
+    let report = ContentFilter::allow_all()
+        .set_max_sanity_level(SanityLevel::Suggestive)
+        .apply(&mut ranking);
+
+    assert_eq!(report.kept, 2);
+    assert_eq!(ids(&ranking), vec![75523989, 81000001]);
+}
+
+/// The ranking with the first, all-ages and not AI-generated, illustration's `field` set to `value`.
+fn ranking_with(field: &str, value: u32) -> IllustrationRanking {
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/ranking_mixed.json")).unwrap();
+    json["illusts"][0][field] = value.into();
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn test_unknown_values_are_filtered() {
+    let mut ranking = ranking_with("x_restrict", 3);
+    assert_eq!(ranking.items()[0].x_restrict(), XRestrict::Unknown(3));
+    ContentFilter::default().apply(&mut ranking);
+    assert!(ids(&ranking).is_empty());
+
+    let mut ranking = ranking_with("illust_ai_type", 3);
+    assert_eq!(ranking.items()[0].illust_ai_type(), AiType::Unknown(3));
+    ContentFilter::default().apply(&mut ranking);
+    assert!(ids(&ranking).is_empty());
+
+    // An unknown restriction could be either, so both have to be allowed.
+    let mut ranking = ranking_with("x_restrict", 3);
+    ContentFilter::default()
+        .set_allow_r18(true)
+        .apply(&mut ranking);
+    assert!(!ids(&ranking).contains(&75523989));
+
+    let mut ranking = ranking_with("x_restrict", 3);
+    ContentFilter::default()
+        .set_allow_r18(true)
+        .set_allow_r18g(true)
+        .apply(&mut ranking);
+    assert!(ids(&ranking).contains(&75523989));
+
+    let mut ranking = ranking_with("illust_ai_type", 3);
+    ContentFilter::default()
+        .set_allow_ai(true)
+        .apply(&mut ranking);
+    assert_eq!(ids(&ranking), vec![75523989, 81000001]);
+
+    // Works older than the AI labels have no `illust_ai_type` and are not AI-generated.
+    let mut ranking = ranking_with("illust_ai_type", 0);
+    ContentFilter::default().apply(&mut ranking);
+    assert_eq!(ids(&ranking), vec![75523989]);
+}
+
+fn novel_ranking() -> NovelRanking {
+    serde_json::from_str(include_str!("fixtures/novel_ranking.json"))
+        .expect("Failed to parse as json.")
+}
+
+fn novel_ids(ranking: &NovelRanking) -> Vec<u32> {
+    ranking.items().iter().map(|novel| novel.id()).collect()
+}
+
+#[test]
+fn test_novel_filter() {
+    let mut ranking = novel_ranking();
+    let report = ContentFilter::default().apply(&mut ranking);
+    assert_eq!(
+        report,
+        FilterReport {
+            kept: 1,
+            dropped: 1
+        }
+    );
+    assert_eq!(novel_ids(&ranking), vec![15925486]);
+
+    // Novels are not rated, so a maximum sanity level does not apply to them.
+    let mut ranking = novel_ranking();
+    ContentFilter::allow_all()
+        .set_max_sanity_level(SanityLevel::Unchecked)
+        .apply(&mut ranking);
+    assert_eq!(novel_ids(&ranking), vec![15925486, 15925999]);
+
+    let mut ranking = novel_ranking();
+    ContentFilter::allow_all()
+        .mute_tag("夏")
+        .apply(&mut ranking);
+    assert!(novel_ids(&ranking).is_empty());
+}
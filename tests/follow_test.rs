@@ -0,0 +1,52 @@
+use pixieve_rs::enums::Visibility;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::follow_detail_proxy::FollowDetailProxy;
+
+use http::Method;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_follow_detail() {
+    let detail = strict_fixture::<FollowDetailProxy>(include_str!("fixtures/follow_detail.json"))
+        .into_inner();
+
+    assert!(detail.is_followed());
+    assert_eq!(detail.restrict(), &Visibility::Private);
+}
+
+#[test]
+fn test_request_follow_add_and_delete() {
+    let request = PixivRequestBuilder::request_adding_follow(6996493, Visibility::Private);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.url.path(), "/v1/user/follow/add");
+    assert_eq!(
+        request.form.get("user_id").map(String::as_str),
+        Some("6996493")
+    );
+    assert_eq!(
+        request.form.get("restrict").map(String::as_str),
+        Some("private")
+    );
+
+    let request = PixivRequestBuilder::request_delete_follow(6996493);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.url.path(), "/v1/user/follow/delete");
+    assert!(!request.form.contains_key("restrict"));
+}
+
+#[test]
+fn test_request_follower_and_mypixiv() {
+    let request = PixivRequestBuilder::request_user_follower(6996493, 30);
+    assert_eq!(request.url.path(), "/v1/user/follower");
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("30"));
+
+    let request = PixivRequestBuilder::request_user_mypixiv(6996493, 0);
+    assert_eq!(request.url.path(), "/v1/user/mypixiv");
+    assert_eq!(
+        request.params.get("user_id").map(String::as_str),
+        Some("6996493")
+    );
+}
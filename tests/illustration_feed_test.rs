@@ -0,0 +1,92 @@
+use pixieve_rs::enums::{ContentType, Visibility};
+use pixieve_rs::pixiv::arg::illustration_following_request_arg::IllustrationFollowingRequestArg;
+use pixieve_rs::pixiv::arg::new_illustrations_request_arg::NewIllustrationsRequestArg;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::following_illustrations::FollowingIllustrations;
+use pixieve_rs::pixiv::result::new_illustrations::NewIllustrations;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_new_illustrations() {
+    let page: NewIllustrations = strict_fixture(include_str!("fixtures/new_illusts.json"));
+
+    assert_eq!(
+        page.illusts()
+            .iter()
+            .map(|illust| illust.id())
+            .collect::<Vec<_>>(),
+        vec![80171413, 75523989]
+    );
+    let request = PixivRequestBuilder::request_next_page(Paginated::next_url(&page).unwrap());
+    assert_eq!(request.url.path(), "/v1/illust/new");
+    assert_eq!(
+        request.url.query(),
+        Some("content_type=illust&filter=for_ios&max_illust_id=75523988")
+    );
+}
+
+#[test]
+fn test_request_new_illustrations() {
+    let request = PixivRequestBuilder::request_new_illustrations(NewIllustrationsRequestArg::new());
+    assert_eq!(request.url.path(), "/v1/illust/new");
+    assert_eq!(
+        request.params.get("content_type").map(String::as_str),
+        Some("illust")
+    );
+    assert_eq!(
+        request.params.get("filter").map(String::as_str),
+        Some("for_ios")
+    );
+    assert!(!request.params.contains_key("max_illust_id"));
+
+    let request = PixivRequestBuilder::request_new_illustrations(
+        NewIllustrationsRequestArg::new()
+            .set_content_type(ContentType::Manga)
+            .set_max_illust_id(75523988),
+    );
+    assert_eq!(
+        request.params.get("content_type").map(String::as_str),
+        Some("manga")
+    );
+    assert_eq!(
+        request.params.get("max_illust_id").map(String::as_str),
+        Some("75523988")
+    );
+}
+
+#[test]
+fn test_following_illustrations() {
+    let page: FollowingIllustrations = strict_fixture(include_str!("fixtures/illust_follow.json"));
+
+    assert_eq!(page.items().len(), 2);
+    assert_eq!(
+        Paginated::next_url(&page)
+            .and_then(|url| url.query_pairs().find(|(k, _)| k == "offset"))
+            .map(|(_, v)| v.into_owned()),
+        Some("30".to_string())
+    );
+}
+
+#[test]
+fn test_request_illustration_following() {
+    let request = PixivRequestBuilder::request_illustration_following(true);
+    assert_eq!(request.url.path(), "/v2/illust/follow");
+    assert_eq!(
+        request.params.get("restrict").map(String::as_str),
+        Some("public")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("0"));
+
+    let request = PixivRequestBuilder::request_illustration_following(
+        IllustrationFollowingRequestArg::new(Visibility::Private).set_offset(30),
+    );
+    assert_eq!(
+        request.params.get("restrict").map(String::as_str),
+        Some("private")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("30"));
+}
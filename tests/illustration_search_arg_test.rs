@@ -0,0 +1,43 @@
+use pixieve_rs::enums::{Filter, SearchAiType, SearchSort};
+use pixieve_rs::pixiv::arg::illustration_search_request_arg::IllustrationSearchRequestArg;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+
+use chrono::NaiveDate;
+
+#[test]
+fn test_full_search_params() {
+    let request = PixivRequestBuilder::request_illustration_search(
+        IllustrationSearchRequestArg::new("風景")
+            .set_sort(SearchSort::PopularFemaleDescending)
+            .set_start_date(NaiveDate::from_ymd_opt(2019, 7, 1).unwrap())
+            .set_end_date(NaiveDate::from_ymd_opt(2019, 7, 31).unwrap())
+            .set_min_bookmarks(1000)
+            .set_max_bookmarks(4999)
+            .set_search_ai_type(SearchAiType::Exclude)
+            .set_merge_plain_keyword_results(true)
+            .set_include_translated_tag_results(false)
+            .set_filter(Filter::ForiOS),
+    );
+    let param = |key: &str| request.params.get(key).map(String::as_str);
+
+    assert_eq!(request.url.path(), "/v1/search/illust");
+    assert_eq!(param("sort"), Some("popular_female_desc"));
+    assert_eq!(param("start_date"), Some("2019-07-01"));
+    assert_eq!(param("end_date"), Some("2019-07-31"));
+    assert_eq!(param("bookmark_num_min"), Some("1000"));
+    assert_eq!(param("bookmark_num_max"), Some("4999"));
+    assert_eq!(param("search_ai_type"), Some("1"));
+    assert_eq!(param("merge_plain_keyword_results"), Some("true"));
+    assert_eq!(param("include_translated_tag_results"), Some("false"));
+    assert_eq!(param("filter"), Some("for_ios"));
+}
+
+#[test]
+fn test_unset_search_params_are_omitted() {
+    let params = IllustrationSearchRequestArg::new("風景")
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(params, vec!["word", "search_target", "sort"]);
+}
@@ -171,7 +171,7 @@ fn test_fetch_illustrations_ranking() {
 
     let args = IllustrationRankingRequestArg::default();
 
-    let request = PixivRequestBuilder::request_illustrations_ranking(args);
+    let request = PixivRequestBuilder::request_illustrations_ranking(args).unwrap();
 
     pixiv
         .execute_with_auth(request)
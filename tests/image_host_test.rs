@@ -0,0 +1,94 @@
+use pixieve_rs::pixiv::client::PixivClient;
+use pixieve_rs::pixiv::image_host::rewrite_image_host;
+use pixieve_rs::pixiv::result::illustration_proxy::IllustrationProxy;
+
+use serde_json::Value;
+
+const MIRROR: &str = "https://pximg.example.com/";
+
+fn collect_urls(value: &Value, urls: &mut Vec<String>) {
+    match value {
+        Value::String(s) if s.starts_with("http") => urls.push(s.clone()),
+        Value::Array(values) => values.iter().for_each(|v| collect_urls(v, urls)),
+        Value::Object(map) => map.values().for_each(|v| collect_urls(v, urls)),
+        _ => (),
+    }
+}
+
+#[test]
+fn test_rewrite_image_host() {
+    assert_eq!(
+        rewrite_image_host(
+            "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p0.png",
+            MIRROR
+        ),
+        "https://pximg.example.com/img-original/img/2019/07/10/00/00/05/75523989_p0.png"
+    );
+    assert_eq!(
+        rewrite_image_host("https://s.pximg.net/common/images/no_profile.png", MIRROR),
+        "https://s.pximg.net/common/images/no_profile.png"
+    );
+    assert_eq!(
+        rewrite_image_host("https://i.pximg.net.evil.com/a.png", MIRROR),
+        "https://i.pximg.net.evil.com/a.png"
+    );
+}
+
+#[test]
+fn test_apply_image_host() {
+    let mut pixiv = PixivClient::new().unwrap();
+    pixiv.set_image_host(MIRROR);
+
+    let proxy: IllustrationProxy =
+        serde_json::from_str(include_str!("fixtures/illust_detail.json")).unwrap();
+    let proxy = pixiv.apply_image_host(proxy);
+
+    let mut urls = Vec::new();
+    collect_urls(&serde_json::to_value(&proxy).unwrap(), &mut urls);
+
+    // Image urls, meta pages and the user's profile image.
+    assert_eq!(urls.len(), 12);
+    assert!(urls
+        .iter()
+        .all(|url| url.starts_with("https://pximg.example.com/")));
+}
+
+#[test]
+fn test_apply_image_host_unset() {
+    let pixiv = PixivClient::new().unwrap();
+    assert_eq!(pixiv.image_host(), None);
+
+    let proxy: IllustrationProxy =
+        serde_json::from_str(include_str!("fixtures/illust_detail.json")).unwrap();
+    let proxy = pixiv.apply_image_host(proxy);
+
+    let mut urls = Vec::new();
+    collect_urls(&serde_json::to_value(&proxy).unwrap(), &mut urls);
+
+    assert!(urls
+        .iter()
+        .all(|url| url.starts_with("https://i.pximg.net/")));
+}
+
+#[test]
+fn test_decode_applies_image_host() {
+    let mut pixiv = PixivClient::new().unwrap();
+    pixiv.set_image_host(MIRROR);
+
+    let value = serde_json::from_str(include_str!("fixtures/illust_detail.json")).unwrap();
+    let proxy: IllustrationProxy = pixiv.decode_value(value).unwrap();
+
+    let mut urls = Vec::new();
+    collect_urls(&serde_json::to_value(&proxy).unwrap(), &mut urls);
+
+    assert_eq!(urls.len(), 12);
+    assert!(urls
+        .iter()
+        .all(|url| url.starts_with("https://pximg.example.com/")));
+
+    // Applying the host again leaves the URLs alone.
+    let again = pixiv.apply_image_host(proxy);
+    let mut urls_again = Vec::new();
+    collect_urls(&serde_json::to_value(&again).unwrap(), &mut urls_again);
+    assert_eq!(urls, urls_again);
+}
@@ -0,0 +1,189 @@
+use pixieve_rs::image_proxy::config::ImageProxyConfig;
+use pixieve_rs::image_proxy::server::ImageProxy;
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const IMAGE_PATH: &str = "/img-original/img/2019/07/10/00/00/05/75523989_p0.png";
+const OTHER_IMAGE_PATH: &str = "/img-original/img/2019/07/10/00/00/05/75523989_p1.png";
+
+/// Stand-in for `i.pximg.net`: serves 1KiB of image data, but only with a pixiv referer.
+fn spawn_upstream() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&hits);
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut has_referer = false;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if line.to_lowercase() == "referer: https://www.pixiv.net/\r\n" {
+                    has_referer = true;
+                }
+                line.clear();
+            }
+
+            counter.fetch_add(1, Ordering::SeqCst);
+            if !has_referer {
+                write!(
+                    stream,
+                    "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+                continue;
+            }
+            let body = vec![b'x'; 1024];
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(&body).unwrap();
+        }
+    });
+
+    (base, hits)
+}
+
+fn spawn_proxy(config: ImageProxyConfig) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let proxy = ImageProxy::new(config).unwrap();
+    std::thread::spawn(move || proxy.serve(listener));
+    base
+}
+
+fn cache_size(dir: &std::path::Path) -> u64 {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().metadata().unwrap().len())
+        .sum()
+}
+
+fn fetch(url: &str) -> (u16, Vec<u8>) {
+    let mut response = reqwest::Client::new().get(url).send().unwrap();
+    let mut body = Vec::new();
+    response.read_to_end(&mut body).unwrap();
+    (response.status().as_u16(), body)
+}
+
+#[test]
+fn test_proxy_adds_referer_and_caches() {
+    let (upstream, hits) = spawn_upstream();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let proxy = spawn_proxy(
+        ImageProxyConfig::default()
+            .set_upstream(upstream)
+            .set_cache_dir(cache_dir.path()),
+    );
+
+    let (status, body) = fetch(&format!("{}{}", proxy, IMAGE_PATH));
+    assert_eq!(status, 200);
+    assert_eq!(body, vec![b'x'; 1024]);
+    assert_eq!(cache_size(cache_dir.path()), 1024);
+
+    let (status, body) = fetch(&format!("{}{}", proxy, IMAGE_PATH));
+    assert_eq!(status, 200);
+    assert_eq!(body.len(), 1024);
+
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(std::fs::read_dir(cache_dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_proxy_skips_oversized_entries() {
+    let (upstream, hits) = spawn_upstream();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let proxy = spawn_proxy(
+        ImageProxyConfig::default()
+            .set_upstream(upstream)
+            .set_cache_dir(cache_dir.path())
+            .set_max_entry_size(512),
+    );
+
+    fetch(&format!("{}{}", proxy, IMAGE_PATH));
+    let (status, body) = fetch(&format!("{}{}", proxy, IMAGE_PATH));
+
+    assert_eq!(status, 200);
+    assert_eq!(body.len(), 1024);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    assert_eq!(std::fs::read_dir(cache_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_proxy_evicts_to_cache_size() {
+    let (upstream, _) = spawn_upstream();
+    let cache_dir = tempfile::tempdir().unwrap();
+    let proxy = spawn_proxy(
+        ImageProxyConfig::default()
+            .set_upstream(upstream)
+            .set_cache_dir(cache_dir.path())
+            .set_max_cache_size(1500),
+    );
+
+    fetch(&format!("{}{}", proxy, IMAGE_PATH));
+    assert_eq!(cache_size(cache_dir.path()), 1024);
+    fetch(&format!("{}{}", proxy, OTHER_IMAGE_PATH));
+
+    assert_eq!(cache_size(cache_dir.path()), 1024);
+}
+
+#[test]
+fn test_proxy_rejects_path_traversal() {
+    let (upstream, hits) = spawn_upstream();
+    let proxy = spawn_proxy(ImageProxyConfig::default().set_upstream(upstream));
+
+    // Sent by hand since HTTP clients normalize dot segments away.
+    for path in &["/img-original/../secret", "/img-original/%2E%2e/secret"] {
+        let mut stream = TcpStream::connect(proxy.trim_start_matches("http://")).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 400"));
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_proxy_rejects_oversized_requests() {
+    let (upstream, hits) = spawn_upstream();
+    let proxy = spawn_proxy(ImageProxyConfig::default().set_upstream(upstream));
+    let send = |request: &[u8]| {
+        let mut stream = TcpStream::connect(proxy.trim_start_matches("http://")).unwrap();
+        stream.write_all(request).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+
+    // A request line that never ends.
+    let endless = format!("GET /{}", "a".repeat(20 * 1024));
+    assert!(send(endless.as_bytes()).starts_with("HTTP/1.1 400"));
+
+    let long_header = format!(
+        "GET {} HTTP/1.1\r\nX-Long: {}\r\n\r\n",
+        IMAGE_PATH,
+        "a".repeat(10 * 1024)
+    );
+    assert!(send(long_header.as_bytes()).starts_with("HTTP/1.1 431"));
+
+    let many_headers = format!(
+        "GET {} HTTP/1.1\r\n{}\r\n",
+        IMAGE_PATH,
+        "X-Header: a\r\n".repeat(200)
+    );
+    assert!(send(many_headers.as_bytes()).starts_with("HTTP/1.1 431"));
+
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
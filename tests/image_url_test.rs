@@ -0,0 +1,140 @@
+use pixieve_rs::pixiv::helper_structs::image_url::ImageUrl;
+use pixieve_rs::pixiv::helper_structs::pximg_url::PximgUrl;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+const LARGE: &str = "https://i.pximg.net/c/600x1200_90/img-master/img/2019/07/10/00/00/05/75523989_p0_master1200.jpg";
+const ORIGINAL: &str = "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p0.png";
+
+#[test]
+fn test_parse_master_url() {
+    let url = PximgUrl::parse(LARGE).expect("Failed to parse url.");
+
+    assert_eq!(url.illust_id(), 75523989);
+    assert_eq!(url.page(), 0);
+    assert_eq!(url.base(), "https://i.pximg.net");
+    assert_eq!(url.original_extension(), None);
+    assert_eq!(url.large(), LARGE);
+    assert_eq!(
+        url.square_medium(),
+        "https://i.pximg.net/c/360x360_70/img-master/img/2019/07/10/00/00/05/75523989_p0_square1200.jpg"
+    );
+    assert_eq!(
+        url.medium(),
+        "https://i.pximg.net/c/540x540_70/img-master/img/2019/07/10/00/00/05/75523989_p0_master1200.jpg"
+    );
+    assert_eq!(
+        url.master(),
+        "https://i.pximg.net/img-master/img/2019/07/10/00/00/05/75523989_p0_master1200.jpg"
+    );
+    assert_eq!(
+        url.square(),
+        "https://i.pximg.net/img-master/img/2019/07/10/00/00/05/75523989_p0_square1200.jpg"
+    );
+    assert_eq!(
+        url.original_candidates(),
+        vec![
+            "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p0.png",
+            "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p0.jpg",
+            "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p0.gif",
+        ]
+    );
+}
+
+#[test]
+fn test_parse_original_url() {
+    let url = PximgUrl::parse(ORIGINAL).expect("Failed to parse url.");
+
+    assert_eq!(url.original_extension(), Some("png"));
+    assert_eq!(url.original(), Some(ORIGINAL.to_string()));
+    assert_eq!(url.original_candidates(), vec![ORIGINAL.to_string()]);
+    assert_eq!(url.large(), LARGE);
+}
+
+#[test]
+fn test_page_url() {
+    let url = PximgUrl::parse(ORIGINAL).unwrap().with_page(3);
+
+    assert_eq!(url.page(), 3);
+    assert_eq!(url.original_extension(), None);
+    assert_eq!(
+        url.large(),
+        "https://i.pximg.net/c/600x1200_90/img-master/img/2019/07/10/00/00/05/75523989_p3_master1200.jpg"
+    );
+}
+
+#[test]
+fn test_parse_rejects_unrelated_urls() {
+    assert_eq!(
+        PximgUrl::parse("https://www.pixiv.net/artworks/75523989"),
+        None
+    );
+    assert_eq!(
+        PximgUrl::parse("https://s.pximg.net/common/images/no_profile.png"),
+        None
+    );
+}
+
+#[test]
+fn test_image_url_for_page() {
+    let image_url = ImageUrl {
+        large: None,
+        medium: None,
+        small: None,
+        square_medium: Some(
+            "https://i.pximg.net/c/360x360_70/img-master/img/2019/07/10/00/00/05/75523989_p0_square1200.jpg"
+                .to_string(),
+        ),
+        original: None,
+        extra: Default::default(),
+    };
+
+    let page = image_url.for_page(1).expect("Failed to derive page.");
+
+    assert_eq!(
+        page.large.as_deref(),
+        Some("https://i.pximg.net/c/600x1200_90/img-master/img/2019/07/10/00/00/05/75523989_p1_master1200.jpg")
+    );
+    assert_eq!(page.original, None);
+    assert_eq!(image_url.original_url_candidates().len(), 3);
+}
+
+#[test]
+fn test_probe_original() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+
+    // Stand-in for i.pximg.net that only has the JPEG original.
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request_line = String::new();
+            BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .unwrap();
+            let status = if request_line.contains("_p0.jpg") {
+                "200 OK"
+            } else {
+                "404 Not Found"
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                status
+            )
+            .unwrap();
+        }
+    });
+
+    let url = PximgUrl::parse(LARGE).unwrap().with_base(base.as_str());
+    let found = url.probe_original(&reqwest::Client::new());
+
+    assert_eq!(
+        found,
+        Some(format!(
+            "{}/img-original/img/2019/07/10/00/00/05/75523989_p0.jpg",
+            base
+        ))
+    );
+}
@@ -1,6 +1,7 @@
 use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
 
 #[test]
+#[allow(deprecated)]
 fn test_into_iterator() {
     let slice: &[usize] = &[0, 1, 2];
     let vec = slice.to_owned();
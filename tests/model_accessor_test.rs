@@ -0,0 +1,71 @@
+use pixieve_rs::enums::ContentType;
+use pixieve_rs::pixiv::result::illustration_comment::IllustrationComment;
+use pixieve_rs::pixiv::result::illustration_proxy::IllustrationProxy;
+
+use chrono::Datelike;
+
+#[test]
+fn test_multi_page_illustration() {
+    let illust =
+        serde_json::from_str::<IllustrationProxy>(include_str!("fixtures/illust_detail.json"))
+            .expect("Failed to parse as json.")
+            .into_inner();
+
+    assert_eq!(illust.id(), 75523989);
+    assert_eq!(illust.title(), "夏の日");
+    assert!(matches!(illust.content_type(), ContentType::Illustration));
+    assert_eq!(illust.create_date().year(), 2019);
+    assert_eq!(illust.total_comments(), Some(12));
+    assert!(illust.is_multi_page());
+    assert!(!illust.is_r18());
+    assert!(!illust.is_r18g());
+    assert_eq!(illust.pages().count(), 2);
+    assert_eq!(
+        illust.original_urls(),
+        vec![
+            "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p0.png",
+            "https://i.pximg.net/img-original/img/2019/07/10/00/00/05/75523989_p1.png",
+        ]
+    );
+    assert_eq!(
+        illust.tag_names().collect::<Vec<_>>(),
+        vec!["オリジナル", "女の子"]
+    );
+    assert_eq!(illust.web_url(), "https://www.pixiv.net/artworks/75523989");
+    assert_eq!(illust.user().account(), "artist_account");
+    assert_eq!(
+        illust.user().web_url(),
+        "https://www.pixiv.net/users/6996493"
+    );
+}
+
+#[test]
+fn test_single_page_illustration() {
+    let illust =
+        serde_json::from_str::<IllustrationProxy>(include_str!("fixtures/illust_single_page.json"))
+            .expect("Failed to parse as json.")
+            .into_inner();
+
+    assert!(!illust.is_multi_page());
+    assert!(illust.is_r18());
+    assert_eq!(illust.total_comments(), None);
+    assert_eq!(illust.series().map(|s| s.title()), Some("Night series"));
+    assert_eq!(illust.pages().count(), 1);
+    assert_eq!(
+        illust.original_urls(),
+        vec!["https://i.pximg.net/img-original/img/2020/03/15/21/30/12/80171413_p0.jpg"]
+    );
+    assert_eq!(illust.user().is_followed(), Some(true));
+}
+
+#[test]
+fn test_illustration_comments() {
+    let comments: IllustrationComment =
+        serde_json::from_str(include_str!("fixtures/illust_comments.json"))
+            .expect("Failed to parse as json.");
+
+    assert_eq!(comments.total_comments(), Some(2));
+    assert_eq!(comments.comments()[0].comment(), "素敵です！");
+    assert_eq!(comments.comments()[0].user().name(), "fan");
+    assert_eq!(comments.comments()[1].date().day(), 10);
+}
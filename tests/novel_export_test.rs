@@ -0,0 +1,221 @@
+use pixieve_rs::pixiv::helper_structs::novel::Novel;
+use pixieve_rs::pixiv::novel_export::{EmbeddedImage, Image, NoImages, NovelBook};
+use pixieve_rs::pixiv::novel_markup::NovelDocument;
+use pixieve_rs::pixiv::result::novel_proxy::NovelProxy;
+use pixieve_rs::pixiv::result::novel_text::NovelText;
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+mod common;
+
+use common::strict_fixture;
+
+fn novel() -> Novel {
+    strict_fixture::<NovelProxy>(include_str!("fixtures/novel_detail.json")).into()
+}
+
+fn novel_text() -> NovelText {
+    strict_fixture(include_str!("fixtures/novel_text.json"))
+}
+
+fn images() -> HashMap<EmbeddedImage, Image> {
+    let mut images = HashMap::new();
+    images.insert(
+        EmbeddedImage::Pixiv {
+            illust_id: 75523989,
+            page: Some(2),
+        },
+        Image::from_path("75523989_p1_master1200.jpg", &b"jpeg"[..]),
+    );
+    images
+}
+
+fn read_epub(epub: Vec<u8>) -> zip::ZipArchive<Cursor<Vec<u8>>> {
+    zip::ZipArchive::new(Cursor::new(epub)).expect("Export should be a zip archive.")
+}
+
+fn read_entry(archive: &mut zip::ZipArchive<Cursor<Vec<u8>>>, name: &str) -> String {
+    let mut text = String::new();
+    archive
+        .by_name(name)
+        .unwrap_or_else(|_| panic!("{} should be in the archive.", name))
+        .read_to_string(&mut text)
+        .unwrap();
+    text
+}
+
+#[test]
+fn test_epub_single_novel() {
+    let book = NovelBook::single(&novel(), novel_text().document());
+    let epub = book
+        .write_epub(Cursor::new(Vec::new()), &images())
+        .unwrap()
+        .into_inner();
+    let mut archive = read_epub(epub);
+
+    {
+        let mimetype = archive.by_index(0).unwrap();
+        assert_eq!(mimetype.name(), "mimetype");
+        assert_eq!(mimetype.compression(), zip::CompressionMethod::Stored);
+    }
+    assert_eq!(read_entry(&mut archive, "mimetype"), "application/epub+zip");
+    assert!(read_entry(&mut archive, "META-INF/container.xml").contains("OEBPS/content.opf"));
+
+    let package = read_entry(&mut archive, "OEBPS/content.opf");
+    assert!(package.contains(
+        "<dc:identifier id=\"book-id\">https://www.pixiv.net/novel/show.php?id=15925486</dc:identifier>"
+    ));
+    assert!(package.contains("<dc:title>灯台守の夏</dc:title>"));
+    assert!(package.contains("<dc:creator>夜</dc:creator>"));
+    assert!(package.contains("<meta property=\"dcterms:modified\">2021-08-30T11:00:00Z</meta>"));
+    assert!(package.contains(
+        "<item id=\"image-pixiv-75523989-2\" href=\"images/pixiv-75523989-2.jpg\" media-type=\"image/jpeg\" />"
+    ));
+    assert!(package
+        .contains("<itemref idref=\"novel-1-page-1\" />\n<itemref idref=\"novel-1-page-2\" />"));
+
+    let nav = read_entry(&mut archive, "OEBPS/nav.xhtml");
+    assert!(nav.contains(
+        "<li><a href=\"text/novel-1-page-1.xhtml#chapter-1\">第一章 灯台守の夏</a></li>\n\
+         <li><a href=\"text/novel-1-page-2.xhtml#chapter-2\">第二章</a></li>"
+    ));
+
+    let first = read_entry(&mut archive, "OEBPS/text/novel-1-page-1.xhtml");
+    assert!(first.contains(
+        "<h1>灯台守の夏</h1>\n\
+         <h2 id=\"chapter-1\">第一章 <ruby>灯台守<rt>とうだいもり</rt></ruby>の夏</h2>\n\
+         <p>海が見える。</p>\n\
+         <div class=\"image\"><img src=\"../images/pixiv-75523989-2.jpg\" alt=\"\" /></div>\n\
+         </body>"
+    ));
+
+    let second = read_entry(&mut archive, "OEBPS/text/novel-1-page-2.xhtml");
+    assert!(second.contains("<p>彼は<ruby>漢字<rt>かんじ</rt></ruby>を読んだ。</p>"));
+    assert!(second.contains(
+        "<p>詳しくは<a href=\"https://www.pixiv.net/\">こちら</a>。<a href=\"novel-1-page-1.xhtml\">p. 1</a></p>"
+    ));
+    assert!(second.contains("<p>[unknown:tag] [pixivimage:abc]</p>"));
+    // Uploaded images cannot be linked to, so without the image there is nothing to show.
+    assert!(!second.contains("12345678"));
+
+    let mut image = Vec::new();
+    archive
+        .by_name("OEBPS/images/pixiv-75523989-2.jpg")
+        .unwrap()
+        .read_to_end(&mut image)
+        .unwrap();
+    assert_eq!(image, b"jpeg");
+}
+
+#[test]
+fn test_epub_links_missing_images() {
+    let book = NovelBook::single(&novel(), novel_text().document());
+    let epub = book
+        .write_epub(Cursor::new(Vec::new()), &NoImages)
+        .unwrap()
+        .into_inner();
+    let mut archive = read_epub(epub);
+
+    assert!(archive
+        .by_name("OEBPS/images/pixiv-75523989-2.jpg")
+        .is_err());
+    assert!(!read_entry(&mut archive, "OEBPS/content.opf").contains("image-"));
+    assert!(read_entry(&mut archive, "OEBPS/text/novel-1-page-1.xhtml").contains(
+        "<p class=\"image\"><a href=\"https://www.pixiv.net/artworks/75523989\">https://www.pixiv.net/artworks/75523989</a></p>"
+    ));
+}
+
+#[test]
+fn test_epub_series() {
+    let first = novel();
+    let text = novel_text();
+    let next = text.series_next().expect("Fixture has a next novel.");
+    let book = NovelBook::series(&first, text.document())
+        .add_novel(next, NovelDocument::parse("秋が来た。[newpage]終わり"));
+    assert_eq!(book.title(), "灯台守");
+    assert_eq!(
+        book.novels()
+            .iter()
+            .map(|novel| novel.id())
+            .collect::<Vec<_>>(),
+        vec![15925486, 15926000]
+    );
+
+    let epub = book
+        .write_epub(Cursor::new(Vec::new()), &NoImages)
+        .unwrap()
+        .into_inner();
+    let mut archive = read_epub(epub);
+
+    assert!(read_entry(&mut archive, "OEBPS/content.opf").contains(
+        "<dc:identifier id=\"book-id\">https://www.pixiv.net/novel/series/1234567</dc:identifier>"
+    ));
+    assert!(read_entry(&mut archive, "OEBPS/nav.xhtml").contains(
+        "<ol>\n\
+         <li><a href=\"text/novel-1-page-1.xhtml\">灯台守の夏</a>\n<ol>\n\
+         <li><a href=\"text/novel-1-page-1.xhtml#chapter-1\">第一章 灯台守の夏</a></li>\n\
+         <li><a href=\"text/novel-1-page-2.xhtml#chapter-2\">第二章</a></li>\n\
+         </ol>\n</li>\n\
+         <li><a href=\"text/novel-2-page-1.xhtml\">灯台守の秋</a></li>\n\
+         </ol>"
+    ));
+    assert!(read_entry(&mut archive, "OEBPS/text/novel-2-page-2.xhtml").contains("<p>終わり</p>"));
+}
+
+#[test]
+fn test_markdown() {
+    let book = NovelBook::single(&novel(), novel_text().document());
+    assert_eq!(
+        book.to_markdown(),
+        "# 灯台守の夏\n\
+         \n夜\n\
+         \n## 第一章 灯台守（とうだいもり）の夏\n\
+         \n海が見える。\n\
+         \n[Illustration 75523989, page 2](<https://www.pixiv.net/artworks/75523989>)\n\
+         \n---\n\
+         \n## 第二章\n\
+         \n彼は漢字（かんじ）を読んだ。\n\
+         \n詳しくは[こちら](<https://www.pixiv.net/>)。p. 1\n\
+         \n\\[unknown:tag\\] \\[pixivimage:abc\\]\n"
+    );
+}
+
+#[test]
+fn test_markdown_escapes_text() {
+    let book = NovelBook::new("*題*").set_author("作者").add_novel(
+        &novel(),
+        NovelDocument::parse("- 箇条書きではない\n1. 番号でもない\n<b>太字</b>でもない"),
+    );
+    assert_eq!(
+        book.to_markdown(),
+        "# \\*題\\*\n\
+         \n作者\n\
+         \n\\- 箇条書きではない\n\
+         \n1\\. 番号でもない\n\
+         \n\\<b\\>太字\\</b\\>でもない\n"
+    );
+}
+
+#[test]
+fn test_only_web_links_are_exported() {
+    let document = NovelDocument::parse(
+        "[[jumpuri:罠 > javascript:alert(1)]] [[jumpuri:画像 > data:text/html,<b>x</b>]] \
+         [[jumpuri:公式 > HTTPS://www.pixiv.net/]]",
+    );
+    let book = NovelBook::new("題").add_novel(&novel(), document);
+
+    assert_eq!(
+        book.to_markdown(),
+        "# 題\n\n夜\n\n罠 画像 [公式](<https://www.pixiv.net/>)\n"
+    );
+
+    let epub = book
+        .write_epub(Cursor::new(Vec::new()), &NoImages)
+        .unwrap()
+        .into_inner();
+    let page = read_entry(&mut read_epub(epub), "OEBPS/text/novel-1-page-1.xhtml");
+    assert!(page.contains("<p>罠 画像 <a href=\"https://www.pixiv.net/\">公式</a></p>"));
+    assert!(!page.contains("javascript:"));
+    assert!(!page.contains("data:"));
+}
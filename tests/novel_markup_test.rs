@@ -0,0 +1,298 @@
+use pixieve_rs::enums::AiType;
+use pixieve_rs::errors::DecodeError;
+use pixieve_rs::pixiv::client::PixivClient;
+use pixieve_rs::pixiv::novel_markup::{Node, NovelDocument};
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::novel_text::NovelText;
+use pixieve_rs::pixiv::result::novel_webview::NovelWebview;
+use pixieve_rs::pixiv::schema::{decode, SchemaMode};
+
+use proptest::prelude::*;
+
+mod common;
+
+use common::strict_fixture;
+
+fn text(text: &str) -> Node {
+    Node::Text(text.to_string())
+}
+
+fn novel_text() -> NovelText {
+    strict_fixture(include_str!("fixtures/novel_text.json"))
+}
+
+#[test]
+fn test_parse_fixture() {
+    let novel_text = novel_text();
+    let document = novel_text.document();
+
+    assert!(novel_text.series_prev().is_none());
+    assert_eq!(
+        novel_text.series_next().map(|novel| novel.id()),
+        Some(15926000)
+    );
+    assert_eq!(document.pages().len(), 2);
+    assert_eq!(
+        document.pages()[0].nodes(),
+        &[
+            Node::Chapter(vec![
+                text("第一章 "),
+                Node::Ruby {
+                    base: "灯台守".to_string(),
+                    reading: "とうだいもり".to_string()
+                },
+                text("の夏"),
+            ]),
+            text("\n海が見える。\n"),
+            Node::PixivImage {
+                illust_id: 75523989,
+                page: Some(2)
+            },
+            text("\n"),
+        ]
+    );
+    assert_eq!(
+        document.pages()[1].nodes(),
+        &[
+            text("\n"),
+            Node::Chapter(vec![text("第二章")]),
+            text("\n彼は"),
+            Node::Ruby {
+                base: "漢字".to_string(),
+                reading: "かんじ".to_string()
+            },
+            text("を読んだ。\n"),
+            Node::UploadedImage { image_id: 12345678 },
+            text("\n詳しくは"),
+            Node::JumpUri {
+                text: "こちら".to_string(),
+                url: "https://www.pixiv.net/".to_string()
+            },
+            text("。"),
+            Node::JumpPage(1),
+            text("\n[unknown:tag] [pixivimage:abc]"),
+        ]
+    );
+    assert_eq!(
+        document
+            .chapters()
+            .map(|(page, title)| (page, title.iter().map(Node::plain_text).collect::<String>()))
+            .collect::<Vec<_>>(),
+        vec![
+            (0, "第一章 灯台守の夏".to_string()),
+            (1, "第二章".to_string())
+        ]
+    );
+    assert_eq!(
+        document.pixiv_images().collect::<Vec<_>>(),
+        vec![(75523989, Some(2))]
+    );
+    assert_eq!(
+        document.uploaded_images().collect::<Vec<_>>(),
+        vec![12345678]
+    );
+    assert!(document
+        .plain_text()
+        .starts_with("第一章 灯台守の夏\n海が見える。"));
+}
+
+#[test]
+fn test_malformed_markup_is_text() {
+    for markup in &[
+        "[chapter:never closed",
+        "[[rb:no arrow]]",
+        "[[rb: > empty base]]",
+        "[[rb:broken\n > line]]",
+        "[pixivimage:1-]",
+        "[pixivimage:-1]",
+        "[uploadedimage:]",
+        "[jump:x]",
+        "[[jumpuri:text > url]",
+        "[",
+        "]",
+    ] {
+        let document = NovelDocument::parse(markup);
+        assert_eq!(document.pages()[0].nodes(), &[text(markup)], "{}", markup);
+        assert_eq!(document.to_markup(), *markup);
+    }
+}
+
+#[test]
+fn test_empty_pages() {
+    let document = NovelDocument::parse("[newpage][newpage]");
+    assert_eq!(document.pages().len(), 3);
+    assert!(document.pages().iter().all(|page| page.nodes().is_empty()));
+    assert_eq!(NovelDocument::parse("").pages().len(), 1);
+}
+
+#[test]
+fn test_request_novel_webview() {
+    let request = PixivRequestBuilder::request_novel_webview(15925486);
+    assert_eq!(request.url.path(), "/webview/v2/novel");
+    assert_eq!(
+        request.params.get("id").map(String::as_str),
+        Some("15925486")
+    );
+    assert!(request.params.contains_key("viewer_version"));
+}
+
+#[test]
+fn test_novel_webview() {
+    let value = NovelWebview::payload(include_str!("fixtures/novel_webview.html"))
+        .expect("Fixture should embed a novel.");
+    let mut pixiv = PixivClient::new().unwrap();
+    pixiv.set_schema_mode(SchemaMode::Strict);
+    pixiv.set_image_host("https://i.pixiv.re");
+    let novel: NovelWebview = pixiv
+        .decode_value(value)
+        .expect("Fixture should match the model.");
+
+    assert_eq!(novel.id(), "15925486");
+    assert_eq!(novel.series_title(), Some("灯台守"));
+    assert_eq!(novel.ai_type(), AiType::NotAiGenerated);
+    assert_eq!(novel.rating().bookmark(), 45);
+    // The same body as the retired endpoint sent.
+    assert_eq!(novel.document(), novel_text().document());
+    assert!(novel.series_prev().is_none());
+    assert_eq!(
+        novel.series_next().map(|next| (next.id(), next.title())),
+        Some((15926000, "灯台守の秋"))
+    );
+    assert!(novel.cover_url().starts_with("https://i.pixiv.re/"));
+    assert_eq!(
+        novel.uploaded_image_url(12345678),
+        Some(
+            "https://i.pixiv.re/novel-cover-original/img/2021/08/30/20/00/00/ci12345678_abcdef.png"
+        )
+    );
+    assert_eq!(novel.uploaded_image_url(1), None);
+    assert_eq!(novel.images()["12345678"].sl(), "2");
+}
+
+#[test]
+fn test_novel_webview_nested_unknown_fields() {
+    let mut value = NovelWebview::payload(include_str!("fixtures/novel_webview.html")).unwrap();
+    value["rating"]["comment"] = serde_json::Value::from(3);
+    value["images"]["12345678"]["width"] = serde_json::Value::from(1200);
+    value["seriesNavigation"]["nextNovel"]["isOriginal"] = serde_json::Value::from(true);
+
+    match decode::<NovelWebview>(value.clone(), SchemaMode::Strict) {
+        Err(DecodeError::SchemaDrift(report)) => {
+            let mut unknown: Vec<_> = report.unknown().collect();
+            unknown.sort_unstable();
+            assert_eq!(
+                unknown,
+                vec![
+                    "images.12345678.width",
+                    "rating.comment",
+                    "seriesNavigation.nextNovel.isOriginal"
+                ]
+            );
+        }
+        other => panic!("Expected schema drift, got {:?}", other),
+    }
+
+    let novel: NovelWebview = decode(value, SchemaMode::Lenient).unwrap();
+    assert!(novel.rating().extra().contains_key("comment"));
+    assert!(novel.images()["12345678"].extra().contains_key("width"));
+    assert!(novel
+        .series_next()
+        .unwrap()
+        .extra()
+        .contains_key("isOriginal"));
+}
+
+#[test]
+fn test_novel_webview_without_novel() {
+    assert!(NovelWebview::payload("<html><body>Not found</body></html>").is_err());
+    assert!(NovelWebview::payload("novel: \"text\", novel: [1]").is_err());
+}
+
+// Text that cannot be mistaken for markup, so it reads back as it was written.
+fn plain() -> impl Strategy<Value = String> {
+    "[^\\[\\]>]{1,12}"
+}
+
+// Ruby, link text and urls are trimmed on parsing.
+fn trimmed() -> impl Strategy<Value = String> {
+    "[^\\[\\]>\\s]([^\\[\\]>\\n]{0,6}[^\\[\\]>\\s])?"
+}
+
+fn inline() -> impl Strategy<Value = Node> {
+    prop_oneof![
+        plain().prop_map(Node::Text),
+        (trimmed(), trimmed()).prop_map(|(base, reading)| Node::Ruby { base, reading }),
+        (trimmed(), trimmed()).prop_map(|(text, url)| Node::JumpUri { text, url }),
+    ]
+}
+
+fn node() -> impl Strategy<Value = Node> {
+    prop_oneof![
+        4 => inline(),
+        1 => prop::collection::vec(inline(), 0..4).prop_map(|title| {
+            // Titles are merged like pages are.
+            NovelDocument::from_pages(vec![title]).pages()[0].nodes().to_vec()
+        }).prop_map(Node::Chapter),
+        1 => (any::<u32>(), prop::option::of(any::<u32>()))
+            .prop_map(|(illust_id, page)| Node::PixivImage { illust_id, page }),
+        1 => any::<u64>().prop_map(|image_id| Node::UploadedImage { image_id }),
+        1 => any::<u32>().prop_map(Node::JumpPage),
+    ]
+}
+
+fn document() -> impl Strategy<Value = NovelDocument> {
+    prop::collection::vec(prop::collection::vec(node(), 0..8), 1..4)
+        .prop_map(NovelDocument::from_pages)
+}
+
+// Fragments that look like markup, to stress the parser more than random text does.
+fn markupish() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            Just("[".to_string()),
+            Just("]".to_string()),
+            Just("[[".to_string()),
+            Just("]]".to_string()),
+            Just("[newpage]".to_string()),
+            Just("[chapter:".to_string()),
+            Just("[[rb:".to_string()),
+            Just("[[jumpuri:".to_string()),
+            Just("[pixivimage:".to_string()),
+            Just("[uploadedimage:".to_string()),
+            Just("[jump:".to_string()),
+            Just(" > ".to_string()),
+            Just("-".to_string()),
+            Just("\n".to_string()),
+            "[0-9]{1,3}",
+            "\\PC{0,3}",
+        ],
+        0..24,
+    )
+    .prop_map(|fragments| fragments.concat())
+}
+
+proptest! {
+    #[test]
+    fn prop_markup_round_trips(document in document()) {
+        prop_assert_eq!(NovelDocument::parse(&document.to_markup()), document);
+    }
+
+    #[test]
+    fn prop_parse_is_idempotent(markup in markupish()) {
+        let document = NovelDocument::parse(&markup);
+        let reparsed = NovelDocument::parse(&document.to_markup());
+        prop_assert_eq!(reparsed, document);
+    }
+
+    #[test]
+    fn prop_text_without_brackets_is_one_node(markup in "[^\\[]*") {
+        let document = NovelDocument::parse(&markup);
+        prop_assert_eq!(document.to_markup(), markup.clone());
+        prop_assert_eq!(document.plain_text(), markup);
+    }
+
+    #[test]
+    fn prop_parse_never_panics(markup in any::<String>()) {
+        NovelDocument::parse(&markup);
+    }
+}
@@ -0,0 +1,119 @@
+use pixieve_rs::enums::{AiType, RankingMode, SearchTarget, Visibility, XRestrict};
+use pixieve_rs::pixiv::arg::illustration_ranking_request_arg::IllustrationRankingRequestArg;
+use pixieve_rs::pixiv::arg::illustration_search_request_arg::IllustrationSearchRequestArg;
+use pixieve_rs::pixiv::arg::user_bookmarks_illustration_request_arg::UserBookmarksIllustrationRequestArg;
+use pixieve_rs::pixiv::helper_structs::novel::Novel;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::novel_proxy::NovelProxy;
+use pixieve_rs::pixiv::result::novel_ranking::NovelRanking;
+use pixieve_rs::pixiv::result::user_novels::UserNovels;
+
+use http::Method;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_novel_detail() {
+    let novel: Novel =
+        strict_fixture::<NovelProxy>(include_str!("fixtures/novel_detail.json")).into();
+
+    assert_eq!(novel.id(), 15925486);
+    assert_eq!(novel.title(), "灯台守の夏");
+    assert_eq!(novel.text_length(), 12034);
+    assert_eq!(novel.page_count(), 3);
+    assert!(novel.is_original());
+    assert_eq!(novel.novel_ai_type(), AiType::NotAiGenerated);
+    assert_eq!(novel.series().map(|series| series.title()), Some("灯台守"));
+    assert_eq!(novel.tags()[0].added_by_uploaded_user(), Some(true));
+    assert_eq!(
+        novel.tag_names().collect::<Vec<_>>(),
+        vec!["オリジナル", "夏"]
+    );
+    assert_eq!(novel.user().account(), "yoru_0");
+    assert_eq!(
+        novel.web_url(),
+        "https://www.pixiv.net/novel/show.php?id=15925486"
+    );
+}
+
+#[test]
+fn test_novel_ranking() {
+    let ranking: NovelRanking = strict_fixture(include_str!("fixtures/novel_ranking.json"));
+
+    assert_eq!(ranking.items().len(), 2);
+    let untitled = &ranking.novels()[1];
+    assert!(untitled.series().is_none());
+    assert_eq!(untitled.x_restrict(), XRestrict::R18);
+    assert!(untitled.is_r18());
+    assert!(Paginated::next_url(&ranking).is_some());
+}
+
+#[test]
+fn test_user_novels() {
+    let novels: UserNovels = strict_fixture(include_str!("fixtures/user_novels.json"));
+
+    assert_eq!(novels.user().id(), novels.novels()[0].user().id());
+    assert_eq!(novels.next_url(), None);
+}
+
+#[test]
+fn test_novel_requests() {
+    let request = PixivRequestBuilder::request_novel(15925486);
+    assert_eq!(request.url.path(), "/v2/novel/detail");
+    assert_eq!(
+        request.params.get("novel_id").map(String::as_str),
+        Some("15925486")
+    );
+
+    let request = PixivRequestBuilder::request_novel_search(
+        IllustrationSearchRequestArg::new("灯台").set_search_target(SearchTarget::Text),
+    );
+    assert_eq!(request.url.path(), "/v1/search/novel");
+    assert_eq!(
+        request.params.get("search_target").map(String::as_str),
+        Some("text")
+    );
+
+    let request = PixivRequestBuilder::request_novels_ranking(
+        IllustrationRankingRequestArg::default().set_mode(RankingMode::Weekly),
+    )
+    .unwrap();
+    assert_eq!(request.url.path(), "/v1/novel/ranking");
+    assert_eq!(request.params.get("mode").map(String::as_str), Some("week"));
+
+    let request = PixivRequestBuilder::request_recommended_novel(30, true);
+    assert_eq!(request.url.path(), "/v1/novel/recommended");
+    assert_eq!(
+        request
+            .params
+            .get("include_ranking_novels")
+            .map(String::as_str),
+        Some("true")
+    );
+
+    let request = PixivRequestBuilder::request_user_novels(1039353, 0);
+    assert_eq!(request.url.path(), "/v1/user/novels");
+
+    let request = PixivRequestBuilder::request_user_bookmarks_novel(
+        UserBookmarksIllustrationRequestArg::new(1039353).set_restrict(Visibility::Private),
+    );
+    assert_eq!(request.url.path(), "/v1/user/bookmarks/novel");
+    assert_eq!(
+        request.params.get("restrict").map(String::as_str),
+        Some("private")
+    );
+
+    let request = PixivRequestBuilder::request_adding_novel_bookmark(15925486, Visibility::Public);
+    assert_eq!(request.method, Method::POST);
+    assert_eq!(request.url.path(), "/v2/novel/bookmark/add");
+    assert_eq!(
+        request.form.get("restrict").map(String::as_str),
+        Some("public")
+    );
+
+    let request = PixivRequestBuilder::request_delete_novel_bookmark(15925486);
+    assert_eq!(request.url.path(), "/v1/novel/bookmark/delete");
+}
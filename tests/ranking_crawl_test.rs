@@ -0,0 +1,127 @@
+use pixieve_rs::enums::RankingMode;
+use pixieve_rs::errors::{ApiError, DecodeError};
+use pixieve_rs::pixiv::ranking::{RankingCrawl, RankingSource};
+use pixieve_rs::pixiv::request::PixivRequest;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::illustration_ranking::IllustrationRanking;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn ranking(fixture: &str) -> Result<IllustrationRanking, DecodeError> {
+    Ok(serde_json::from_str(fixture).unwrap())
+}
+
+/// Recorded ranking pages by date, standing in for pixiv.
+#[derive(Default)]
+struct RecordedRankings {
+    pages: HashMap<String, Vec<Result<IllustrationRanking, DecodeError>>>,
+    requests: Vec<PixivRequest>,
+}
+
+impl RankingSource<IllustrationRanking> for &mut RecordedRankings {
+    type Pages = std::vec::IntoIter<Result<IllustrationRanking, DecodeError>>;
+
+    fn ranking_pages(&mut self, first: PixivRequest) -> Self::Pages {
+        let pages = first
+            .params
+            .get("date")
+            .and_then(|date| self.pages.remove(date))
+            .unwrap_or_default();
+        self.requests.push(first);
+        pages.into_iter()
+    }
+}
+
+#[test]
+fn test_ranking_crawl() {
+    let mut recorded = RecordedRankings::default();
+    recorded.pages.insert(
+        "2020-02-27".to_string(),
+        vec![
+            ranking(include_str!("fixtures/ranking_mixed.json")),
+            ranking(include_str!("fixtures/ranking_last_page.json")),
+        ],
+    );
+    recorded.pages.insert(
+        "2020-02-28".to_string(),
+        vec![ranking(include_str!("fixtures/ranking_last_page.json"))],
+    );
+    recorded.pages.insert(
+        "2020-02-29".to_string(),
+        vec![Err(DecodeError::Api(ApiError::new(
+            403,
+            r#"{"error": {"message": "Rate Limit"}}"#,
+        )))],
+    );
+    recorded.pages.insert(
+        "2020-03-01".to_string(),
+        vec![ranking(include_str!("fixtures/ranking_last_page.json"))],
+    );
+
+    let dates = vec![
+        date(2000, 1, 1),
+        date(2020, 2, 27),
+        date(9999, 1, 1),
+        date(2020, 2, 28),
+        date(2020, 2, 29),
+        date(2020, 3, 1),
+    ];
+    let results: Vec<_> = RankingCrawl::new(
+        &mut recorded,
+        RankingMode::Daily,
+        dates,
+        PixivRequestBuilder::request_illustrations_ranking,
+    )
+    .collect();
+
+    let ranked: Vec<_> = results
+        .iter()
+        .filter_map(|result| result.as_ref().ok())
+        .map(|(date, rank, illust)| (*date, *rank, illust.id()))
+        .collect();
+    assert_eq!(
+        ranked,
+        vec![
+            // Ranks go on from one page to the next...
+            (date(2020, 2, 27), 1, 75523989),
+            (date(2020, 2, 27), 2, 80171413),
+            (date(2020, 2, 27), 3, 81000001),
+            (date(2020, 2, 27), 4, 81000002),
+            (date(2020, 2, 27), 5, 75523989),
+            (date(2020, 2, 27), 6, 80171413),
+            // ...and start over every day.
+            (date(2020, 2, 28), 1, 75523989),
+            (date(2020, 2, 28), 2, 80171413),
+        ]
+    );
+    match results.last() {
+        Some(Err(DecodeError::Api(e))) => assert_eq!(e.status(), 403),
+        other => panic!("Expected the crawl to end with the error, got {:?}", other),
+    }
+    assert_eq!(results.len(), ranked.len() + 1);
+
+    // Days without a ranking are never asked for, and nothing is after the error.
+    let requested: Vec<_> = recorded
+        .requests
+        .iter()
+        .map(|request| {
+            (
+                request.params["date"].as_str(),
+                request.params["mode"].as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        requested,
+        vec![
+            ("2020-02-27", "day"),
+            ("2020-02-28", "day"),
+            ("2020-02-29", "day")
+        ]
+    );
+}
@@ -0,0 +1,151 @@
+use pixieve_rs::enums::{pixiv_today, RankingMode};
+use pixieve_rs::errors::RankingDateError;
+use pixieve_rs::pixiv::arg::illustration_ranking_request_arg::IllustrationRankingRequestArg;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::illustration_proxy::IllustrationProxy;
+
+use chrono::NaiveDate;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_validate_ranking_date() {
+    let today = date(2020, 3, 1);
+
+    assert_eq!(
+        RankingMode::Daily.validate_date(date(2020, 2, 29), today),
+        Ok(())
+    );
+    // Today's ranking is published tomorrow.
+    assert_eq!(
+        RankingMode::Daily.validate_date(today, today),
+        Err(RankingDateError::InFuture { date: today, today })
+    );
+    assert_eq!(
+        RankingMode::Daily.validate_date(date(2020, 3, 2), today),
+        Err(RankingDateError::InFuture {
+            date: date(2020, 3, 2),
+            today
+        })
+    );
+    assert_eq!(
+        RankingMode::DayMale.validate_date(date(2010, 1, 1), today),
+        Err(RankingDateError::BeforeEarliest {
+            date: date(2010, 1, 1),
+            earliest: RankingMode::DayMale.earliest_date()
+        })
+    );
+}
+
+#[test]
+fn test_set_ranking_date() {
+    let params = IllustrationRankingRequestArg::default()
+        .set_mode(RankingMode::Weekly)
+        .set_date(date(2019, 7, 10))
+        .expect("Date should be valid.")
+        .build()
+        .expect("Date should be valid.");
+
+    assert_eq!(params.get("date").map(String::as_str), Some("2019-07-10"));
+    assert_eq!(params.get("mode").map(String::as_str), Some("week"));
+
+    assert!(IllustrationRankingRequestArg::default()
+        .set_date(date(9999, 1, 1))
+        .is_err());
+    assert!(IllustrationRankingRequestArg::default()
+        .set_date(date(2000, 1, 1))
+        .is_err());
+    assert!(IllustrationRankingRequestArg::default()
+        .set_date(pixiv_today())
+        .is_err());
+}
+
+#[test]
+fn test_build_checks_date_against_final_mode() {
+    // Valid for the daily ranking the date was set with, but before AI rankings existed.
+    let argument = IllustrationRankingRequestArg::default()
+        .set_date(date(2019, 7, 10))
+        .expect("Date should be valid.")
+        .set_mode(RankingMode::DayAi);
+
+    assert_eq!(
+        argument.build(),
+        Err(RankingDateError::BeforeEarliest {
+            date: date(2019, 7, 10),
+            earliest: RankingMode::DayAi.earliest_date()
+        })
+    );
+    assert!(PixivRequestBuilder::request_illustrations_ranking(
+        IllustrationRankingRequestArg::default()
+            .set_date(date(2019, 7, 10))
+            .unwrap()
+            .set_mode(RankingMode::DayAi)
+    )
+    .is_err());
+}
+
+#[test]
+fn test_decode_create_date() {
+    let proxy: IllustrationProxy =
+        serde_json::from_str(include_str!("fixtures/illust_detail.json")).unwrap();
+    let value = serde_json::to_value(&proxy).unwrap();
+
+    assert_eq!(value["illust"]["create_date"], "2019-07-10T00:00:05+09:00");
+}
+
+#[test]
+fn test_ranking_mode_names() {
+    let modes = [
+        (RankingMode::DayR18, "day_r18"),
+        (RankingMode::WeekR18, "week_r18"),
+        (RankingMode::WeekR18G, "week_r18g"),
+        (RankingMode::DayMaleR18, "day_male_r18"),
+        (RankingMode::DayFemaleR18, "day_female_r18"),
+        (RankingMode::DayAi, "day_ai"),
+        (RankingMode::DayR18Ai, "day_r18_ai"),
+    ];
+    for (mode, name) in modes.iter() {
+        assert_eq!(mode.as_str(), *name);
+        assert_eq!(RankingMode::from(name.to_string()), *mode);
+        assert_eq!(
+            serde_json::to_value(mode).unwrap(),
+            serde_json::Value::from(*name)
+        );
+    }
+
+    assert!(RankingMode::WeekR18G.is_r18());
+    assert!(RankingMode::DayR18Ai.is_r18());
+    assert!(!RankingMode::DayAi.is_r18());
+    assert!(!RankingMode::Daily.is_r18());
+
+    let params = IllustrationRankingRequestArg::default()
+        .set_mode(RankingMode::DayR18)
+        .build()
+        .unwrap();
+    assert_eq!(params.get("mode").map(String::as_str), Some("day_r18"));
+}
+
+#[test]
+fn test_ranking_dates_between() {
+    let today = date(2020, 3, 1);
+
+    assert_eq!(
+        RankingMode::Daily.dates_between(date(2020, 2, 27), date(2020, 3, 5), today),
+        vec![date(2020, 2, 27), date(2020, 2, 28), date(2020, 2, 29)]
+    );
+    assert!(RankingMode::Daily
+        .dates_between(today, today, today)
+        .is_empty());
+    assert_eq!(
+        RankingMode::DayAi.dates_between(date(2022, 10, 30), date(2022, 11, 2), date(2023, 1, 1)),
+        vec![date(2022, 11, 1), date(2022, 11, 2)]
+    );
+    assert!(RankingMode::Daily
+        .dates_between(date(2020, 2, 2), date(2020, 2, 1), today)
+        .is_empty());
+    assert!(RankingMode::DayAi
+        .dates_between(date(2020, 1, 1), date(2020, 2, 1), today)
+        .is_empty());
+}
@@ -0,0 +1,165 @@
+use pixieve_rs::enums::AiType;
+use pixieve_rs::errors::{ApiError, DecodeError};
+use pixieve_rs::pixiv::result::bookmark_tags::BookmarkTags;
+use pixieve_rs::pixiv::result::comment_proxy::CommentProxy;
+use pixieve_rs::pixiv::result::follow_detail_proxy::FollowDetailProxy;
+use pixieve_rs::pixiv::result::following_illustrations::FollowingIllustrations;
+use pixieve_rs::pixiv::result::illustration_bookmark_info_proxy::IllustBookmarkInfoProxy;
+use pixieve_rs::pixiv::result::illustration_comment::IllustrationComment;
+use pixieve_rs::pixiv::result::illustration_proxy::IllustrationProxy;
+use pixieve_rs::pixiv::result::illustration_ranking::IllustrationRanking;
+use pixieve_rs::pixiv::result::illustration_search_proxy::IllustrationSearchProxy;
+use pixieve_rs::pixiv::result::illustration_series::IllustrationSeries;
+use pixieve_rs::pixiv::result::new_illustrations::NewIllustrations;
+use pixieve_rs::pixiv::result::novel_proxy::NovelProxy;
+use pixieve_rs::pixiv::result::novel_ranking::NovelRanking;
+use pixieve_rs::pixiv::result::novel_series::NovelSeries;
+use pixieve_rs::pixiv::result::novel_text::NovelText;
+use pixieve_rs::pixiv::result::popular_preview::PopularPreview;
+use pixieve_rs::pixiv::result::recommended_illustration::RecommendedIllustration;
+use pixieve_rs::pixiv::result::search_autocomplete::SearchAutocomplete;
+use pixieve_rs::pixiv::result::spotlight_articles::SpotlightArticles;
+use pixieve_rs::pixiv::result::user_bookmarks_illustration::UserBookmarksIllustration;
+use pixieve_rs::pixiv::result::user_detail::UserDetail;
+use pixieve_rs::pixiv::result::user_illustrations::UserIllustrations;
+use pixieve_rs::pixiv::result::user_novels::UserNovels;
+use pixieve_rs::pixiv::result::user_previews::UserPreviews;
+use pixieve_rs::pixiv::schema::{decode, SchemaMode};
+
+use serde_json::Value;
+
+fn drifted() -> Value {
+    serde_json::from_str(include_str!("fixtures/illust_detail_drift.json")).unwrap()
+}
+
+#[test]
+fn test_unknown_fields_are_collected() {
+    let illust = decode::<IllustrationProxy>(drifted(), SchemaMode::Lenient)
+        .expect("Lenient decoding should not fail.")
+        .into_inner();
+
+    assert_eq!(illust.illust_ai_type(), AiType::NotAiGenerated);
+    assert!(!illust.extra().contains_key("illust_ai_type"));
+    assert!(illust.extra().contains_key("illust_book_style"));
+    assert!(illust.extra().contains_key("restriction_attributes"));
+    assert!(illust.tags()[0]
+        .extra()
+        .contains_key("is_pixpedia_article_exists"));
+    assert!(illust.user().extra().is_empty());
+}
+
+#[test]
+fn test_warn_mode_decodes() {
+    assert!(decode::<IllustrationProxy>(drifted(), SchemaMode::Warn).is_ok());
+}
+
+#[test]
+fn test_strict_mode_reports_drift() {
+    let report = match decode::<IllustrationProxy>(drifted(), SchemaMode::Strict) {
+        Err(DecodeError::SchemaDrift(report)) => report,
+        other => panic!("Expected schema drift, got {:?}", other),
+    };
+
+    assert_eq!(
+        report.unknown().collect::<Vec<_>>(),
+        vec![
+            "illust.illust_book_style",
+            "illust.restriction_attributes",
+            "illust.tags[].is_pixpedia_article_exists",
+        ]
+    );
+    assert_eq!(report.missing().collect::<Vec<_>>(), vec!["illust.series"]);
+}
+
+#[test]
+fn test_strict_mode_accepts_known_schema() {
+    let value = serde_json::from_str(include_str!("fixtures/illust_detail.json")).unwrap();
+    assert!(decode::<IllustrationProxy>(value, SchemaMode::Strict).is_ok());
+}
+
+#[test]
+fn test_api_error() {
+    let error = ApiError::new(404, include_str!("fixtures/api_error.json"));
+    assert_eq!(error.status(), 404);
+    assert_eq!(
+        error.message(),
+        Some("該当作品は削除されたか、存在しない作品IDです。")
+    );
+    assert!(error
+        .to_string()
+        .starts_with("pixiv returned 404: 該当作品"));
+
+    let error = ApiError::new(
+        400,
+        r#"{"error": {"user_message": "", "message": "Error occurred at the OAuth process.", "reason": ""}}"#,
+    );
+    assert_eq!(
+        error.message(),
+        Some("Error occurred at the OAuth process.")
+    );
+
+    let error = ApiError::new(502, "<html>Bad Gateway</html>");
+    assert_eq!(error.message(), None);
+    assert_eq!(error.body(), &Value::from("<html>Bad Gateway</html>"));
+}
+
+/// Decode a fixture strictly, noting the fixture and the drift on failure.
+macro_rules! check_strict {
+    ($failures:ident, $fixture:literal, $model:ty) => {
+        let value: Value =
+            serde_json::from_str(include_str!(concat!("fixtures/", $fixture))).unwrap();
+        if let Err(e) = decode::<$model>(value, SchemaMode::Strict) {
+            $failures.push(format!("{}: {}", $fixture, e));
+        }
+    };
+}
+
+#[test]
+fn test_strict_mode_accepts_every_fixture() {
+    let mut failures = Vec::<String>::new();
+    check_strict!(
+        failures,
+        "bookmark_detail_unknown_restrict.json",
+        IllustBookmarkInfoProxy
+    );
+    check_strict!(failures, "bookmark_tags.json", BookmarkTags);
+    check_strict!(failures, "comment_add.json", CommentProxy);
+    check_strict!(failures, "comment_replies.json", IllustrationComment);
+    check_strict!(failures, "comments_without_total.json", IllustrationComment);
+    check_strict!(failures, "follow_detail.json", FollowDetailProxy);
+    check_strict!(failures, "illust_comments.json", IllustrationComment);
+    check_strict!(failures, "illust_comments_v2.json", IllustrationComment);
+    check_strict!(failures, "illust_detail.json", IllustrationProxy);
+    check_strict!(failures, "illust_follow.json", FollowingIllustrations);
+    check_strict!(failures, "illust_series.json", IllustrationSeries);
+    check_strict!(failures, "illust_series_last_page.json", IllustrationSeries);
+    check_strict!(failures, "illust_single_page.json", IllustrationProxy);
+    check_strict!(failures, "new_illusts.json", NewIllustrations);
+    check_strict!(failures, "novel_detail.json", NovelProxy);
+    check_strict!(failures, "novel_ranking.json", NovelRanking);
+    check_strict!(failures, "novel_series.json", NovelSeries);
+    check_strict!(failures, "novel_text.json", NovelText);
+    check_strict!(failures, "popular_preview.json", PopularPreview);
+    check_strict!(failures, "ranking_last_page.json", IllustrationRanking);
+    check_strict!(failures, "ranking_mixed.json", IllustrationRanking);
+    check_strict!(failures, "recommended.json", RecommendedIllustration);
+    check_strict!(failures, "search_autocomplete.json", SearchAutocomplete);
+    check_strict!(
+        failures,
+        "search_empty_next_url.json",
+        IllustrationSearchProxy
+    );
+    check_strict!(failures, "spotlight_articles.json", SpotlightArticles);
+    check_strict!(
+        failures,
+        "user_bookmarks_illust.json",
+        UserBookmarksIllustration
+    );
+    check_strict!(failures, "user_detail.json", UserDetail);
+    check_strict!(failures, "user_illusts.json", UserIllustrations);
+    check_strict!(failures, "user_novels.json", UserNovels);
+    check_strict!(failures, "user_previews.json", UserPreviews);
+    check_strict!(failures, "user_search.json", UserPreviews);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
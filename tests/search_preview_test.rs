@@ -0,0 +1,54 @@
+use pixieve_rs::enums::SearchTarget;
+use pixieve_rs::pixiv::arg::illustration_search_request_arg::IllustrationSearchRequestArg;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::popular_preview::PopularPreview;
+use pixieve_rs::pixiv::result::search_autocomplete::SearchAutocomplete;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_popular_preview() {
+    let preview: PopularPreview = strict_fixture(include_str!("fixtures/popular_preview.json"));
+
+    assert_eq!(preview.illusts().len(), 2);
+    assert!(preview.illusts()[0].total_bookmarks() > 0);
+}
+
+#[test]
+fn test_request_popular_preview() {
+    let request = PixivRequestBuilder::request_illustration_popular_preview(
+        IllustrationSearchRequestArg::new("風景").set_search_target(SearchTarget::TagsExact),
+    );
+
+    assert_eq!(request.url.path(), "/v1/search/popular-preview/illust");
+    assert_eq!(request.params.get("word").map(String::as_str), Some("風景"));
+    assert_eq!(
+        request.params.get("search_target").map(String::as_str),
+        Some("exact_match_for_tags")
+    );
+}
+
+#[test]
+fn test_search_autocomplete() {
+    let autocomplete: SearchAutocomplete =
+        strict_fixture(include_str!("fixtures/search_autocomplete.json"));
+
+    assert_eq!(
+        autocomplete
+            .tags()
+            .iter()
+            .map(|tag| (tag.name(), tag.translated_name()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("風景", Some("landscape")),
+            ("風景画", None),
+            ("風景写真", Some("landscape photography")),
+        ]
+    );
+
+    let request = PixivRequestBuilder::request_search_autocomplete("風景");
+    assert_eq!(request.url.path(), "/v2/search/autocomplete");
+    assert_eq!(request.params.get("word").map(String::as_str), Some("風景"));
+}
@@ -0,0 +1,249 @@
+use pixieve_rs::errors::SearchQueryError;
+use pixieve_rs::pixiv::arg::illustration_search_request_arg::IllustrationSearchRequestArg;
+use pixieve_rs::pixiv::search_query::{SearchQuery, Term};
+
+use proptest::prelude::*;
+
+fn parse(query: &str) -> SearchQuery {
+    query.parse().expect("Query should parse.")
+}
+
+#[test]
+fn test_render() {
+    let query = SearchQuery::new()
+        .tag("オリジナル")
+        .any_of(vec!["夏", "海", "空"])
+        .exclude("R-18")
+        .exclude("AI generated")
+        .phrase("summer \"vibes\"")
+        .users_iri(5000);
+
+    assert_eq!(
+        query.to_string(),
+        r#"オリジナル (夏 OR 海 OR 空) -R-18 -"AI generated" "summer \"vibes\"" 5000users入り"#
+    );
+}
+
+#[test]
+fn test_tags_that_need_quotes() {
+    let query = SearchQuery::new()
+        .tag("OR")
+        .tag("-minus")
+        .tag("a(b)")
+        .tag("full　width");
+
+    assert_eq!(query.to_string(), r#""OR" "-minus" "a(b)" "full　width""#);
+    assert!(query
+        .terms()
+        .iter()
+        .all(|term| matches!(term, Term::Phrase(_))));
+}
+
+#[test]
+fn test_any_of_edge_cases() {
+    assert!(SearchQuery::new().any_of(Vec::<String>::new()).is_empty());
+    assert_eq!(
+        SearchQuery::new().any_of(vec!["夏"]).terms(),
+        &[Term::Tag("夏".to_string())]
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        parse("風景　夏 OR 海  -R-18 \"blue sky\" 1000users入り").terms(),
+        &[
+            Term::Tag("風景".to_string()),
+            Term::Or(vec![
+                Term::Tag("夏".to_string()),
+                Term::Tag("海".to_string())
+            ]),
+            Term::Exclude("R-18".to_string()),
+            Term::Phrase("blue sky".to_string()),
+            Term::UsersIri(1000),
+        ]
+    );
+    assert_eq!(
+        parse("(a OR b) OR (c OR \"d e\")").terms(),
+        &[Term::Or(vec![
+            Term::Tag("a".to_string()),
+            Term::Tag("b".to_string()),
+            Term::Tag("c".to_string()),
+            Term::Phrase("d e".to_string()),
+        ])]
+    );
+    assert_eq!(parse("(a b) c"), parse("a b c"));
+    assert_eq!(
+        parse("users入り xusers入り").terms(),
+        &[
+            Term::Tag("users入り".to_string()),
+            Term::Tag("xusers入り".to_string()),
+        ]
+    );
+    assert!(parse("  ").is_empty());
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |query: &str| query.parse::<SearchQuery>().unwrap_err();
+
+    assert_eq!(
+        error("a \"b"),
+        SearchQueryError::UnclosedQuote { position: 2 }
+    );
+    assert_eq!(
+        error("(a OR b"),
+        SearchQueryError::UnclosedParen { position: 0 }
+    );
+    assert_eq!(
+        error("a)"),
+        SearchQueryError::UnexpectedParen { position: 1 }
+    );
+    assert_eq!(error("OR a"), SearchQueryError::DanglingOr { position: 0 });
+    assert_eq!(error("a OR"), SearchQueryError::DanglingOr { position: 2 });
+    assert_eq!(
+        error("a OR OR b"),
+        SearchQueryError::DanglingOr { position: 5 }
+    );
+    assert_eq!(
+        error("a - b"),
+        SearchQueryError::EmptyExclusion { position: 2 }
+    );
+    assert_eq!(
+        error("a OR (b c)"),
+        SearchQueryError::GroupInOr { position: 5 }
+    );
+    assert_eq!(
+        error("(b c) OR a"),
+        SearchQueryError::GroupInOr { position: 0 }
+    );
+}
+
+#[test]
+fn test_round_trip_from_builder() {
+    let queries = vec![
+        SearchQuery::new(),
+        SearchQuery::new().tag("風景"),
+        SearchQuery::new()
+            .tag("Fate/GO")
+            .any_of(vec!["セイバー", "\"quoted\"", "back\\slash"])
+            .exclude("-already-minus")
+            .phrase("")
+            .users_iri(0),
+        SearchQuery::new()
+            .tag("OR")
+            .tag("1000users入り")
+            .term(Term::Exclude("a OR b".to_string())),
+        SearchQuery::new().exclude("").tag("a"),
+        SearchQuery::new()
+            .term(Term::Or(vec![]))
+            .term(Term::Or(vec![Term::Tag("a b".to_string())]))
+            .term(Term::Or(vec![
+                Term::Or(vec![Term::Tag("x".to_string()), Term::UsersIri(10)]),
+                Term::Exclude(String::new()),
+                Term::Exclude("y".to_string()),
+            ])),
+    ];
+
+    for query in queries {
+        let rendered = query.to_string();
+        assert_eq!(rendered.parse::<SearchQuery>(), Ok(query), "{}", rendered);
+    }
+}
+
+#[test]
+fn test_builder_skips_empty_terms() {
+    assert!(SearchQuery::new().exclude("").is_empty());
+    assert!(SearchQuery::new().term(Term::Or(vec![])).is_empty());
+    assert_eq!(
+        SearchQuery::new()
+            .term(Term::Or(vec![Term::Tag("a".to_string())]))
+            .terms(),
+        &[Term::Tag("a".to_string())]
+    );
+}
+
+fn text() -> impl Strategy<Value = String> {
+    prop_oneof![
+        r#"[a-cOR \\"()\-　]{0,6}"#,
+        Just("OR".to_string()),
+        Just(String::new()),
+        (0u32..10000).prop_map(|count| format!("{}users入り", count)),
+    ]
+}
+
+fn term() -> impl Strategy<Value = Term> {
+    let leaf = prop_oneof![
+        text().prop_map(Term::Tag),
+        text().prop_map(Term::Phrase),
+        text().prop_map(Term::Exclude),
+        any::<u32>().prop_map(Term::UsersIri),
+    ];
+    leaf.prop_recursive(3, 12, 4, |inner| {
+        prop::collection::vec(inner, 0..4).prop_map(Term::Or)
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Tag(String),
+    Phrase(String),
+    Exclude(String),
+    AnyOf(Vec<String>),
+    UsersIri(u32),
+    Term(Term),
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        text().prop_map(Step::Tag),
+        text().prop_map(Step::Phrase),
+        text().prop_map(Step::Exclude),
+        prop::collection::vec(text(), 0..4).prop_map(Step::AnyOf),
+        any::<u32>().prop_map(Step::UsersIri),
+        term().prop_map(Step::Term),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn prop_builder_round_trips(steps in prop::collection::vec(step(), 0..6)) {
+        let query = steps.into_iter().fold(SearchQuery::new(), |query, step| match step {
+            Step::Tag(text) => query.tag(text),
+            Step::Phrase(text) => query.phrase(text),
+            Step::Exclude(text) => query.exclude(text),
+            Step::AnyOf(texts) => query.any_of(texts),
+            Step::UsersIri(count) => query.users_iri(count),
+            Step::Term(term) => query.term(term),
+        });
+        let rendered = query.to_string();
+        prop_assert_eq!(rendered.parse::<SearchQuery>(), Ok(query), "{}", rendered);
+    }
+}
+
+#[test]
+fn test_round_trip_from_string() {
+    let queries = vec![
+        ("a b", "a b"),
+        ("a　 b", "a b"),
+        ("a OR b", "(a OR b)"),
+        ("(a) -\"b\"", "a -b"),
+        ("\"a\\\\b\" -\"c d\"", "\"a\\\\b\" -\"c d\""),
+        ("x (y OR z) OR w", "x (y OR z OR w)"),
+    ];
+
+    for (query, canonical) in queries {
+        let parsed = parse(query);
+        assert_eq!(parsed.to_string(), canonical);
+        assert_eq!(parse(canonical), parsed);
+    }
+}
+
+#[test]
+fn test_search_arg_from_query() {
+    let params = IllustrationSearchRequestArg::new(SearchQuery::new().tag("a").exclude("b"))
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(params[0], ("word", "a -b".to_string()));
+}
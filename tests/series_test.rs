@@ -0,0 +1,198 @@
+use pixieve_rs::enums::AiType;
+use pixieve_rs::errors::DownloadError;
+use pixieve_rs::pixiv::client::PixivClient;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::illustration_series::IllustrationSeries;
+use pixieve_rs::pixiv::result::novel_series::NovelSeries;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+mod common;
+
+use common::strict_fixture;
+
+fn illustration_series(fixture: &str) -> IllustrationSeries {
+    strict_fixture(fixture)
+}
+
+#[test]
+fn test_illustration_series() {
+    let page = illustration_series(include_str!("fixtures/illust_series.json"));
+    let detail = page.detail();
+
+    assert_eq!(detail.id(), 98765);
+    assert_eq!(detail.title(), "夏の記録");
+    assert_eq!(detail.series_work_count(), 3);
+    assert!(!detail.watchlist_added());
+    assert!(detail.cover_image_urls().medium.is_some());
+    assert_eq!(
+        detail.web_url(),
+        format!(
+            "https://www.pixiv.net/user/{}/series/98765",
+            detail.user().id()
+        )
+    );
+    assert_eq!(page.first_illust().id(), 90000001);
+    assert_eq!(
+        page.first_illust().series().map(|series| series.id()),
+        Some(98765)
+    );
+    assert_eq!(page.items().len(), 2);
+    assert_eq!(
+        Paginated::next_url(&page)
+            .and_then(|url| url.query_pairs().find(|(k, _)| k == "offset"))
+            .map(|(_, v)| v.into_owned()),
+        Some("2".to_string())
+    );
+}
+
+#[test]
+fn test_illustration_series_reading_order() {
+    let mut series = illustration_series(include_str!("fixtures/illust_series.json"));
+    series.append(illustration_series(include_str!(
+        "fixtures/illust_series_last_page.json"
+    )));
+
+    assert!(series.next_url().is_none());
+    assert_eq!(
+        series
+            .illusts()
+            .iter()
+            .map(|illust| illust.id())
+            .collect::<Vec<_>>(),
+        vec![90000003, 90000002, 90000001]
+    );
+    assert_eq!(
+        series
+            .reading_order()
+            .iter()
+            .map(|illust| illust.id())
+            .collect::<Vec<_>>(),
+        vec![90000001, 90000002, 90000003]
+    );
+}
+
+#[test]
+fn test_novel_series() {
+    let series: NovelSeries = strict_fixture(include_str!("fixtures/novel_series.json"));
+    let detail = series.detail();
+
+    assert_eq!(detail.id(), 1234567);
+    assert_eq!(detail.title(), "灯台守");
+    assert_eq!(detail.content_count(), 2);
+    assert_eq!(detail.total_character_count(), 20468);
+    assert_eq!(detail.display_text(), "第2話");
+    assert!(detail.is_original());
+    assert!(!detail.is_concluded());
+    assert!(detail.watchlist_added());
+    assert_eq!(detail.novel_ai_type(), AiType::NotAiGenerated);
+    assert_eq!(
+        detail.web_url(),
+        "https://www.pixiv.net/novel/series/1234567"
+    );
+    assert_eq!(series.first_novel().map(|novel| novel.id()), Some(15925486));
+    assert_eq!(
+        series.latest_novel().map(|novel| novel.id()),
+        Some(15926000)
+    );
+    assert_eq!(
+        series
+            .novels()
+            .iter()
+            .map(|novel| novel.id())
+            .collect::<Vec<_>>(),
+        vec![15925486, 15926000]
+    );
+    assert!(Paginated::next_url(&series).is_none());
+}
+
+#[test]
+fn test_request_series() {
+    let request = PixivRequestBuilder::request_illustration_series(98765);
+    assert_eq!(request.url.path(), "/v1/illust/series");
+    assert_eq!(
+        request.params.get("illust_series_id").map(String::as_str),
+        Some("98765")
+    );
+
+    let request = PixivRequestBuilder::request_novel_series(1234567);
+    assert_eq!(request.url.path(), "/v2/novel/series");
+    assert_eq!(
+        request.params.get("series_id").map(String::as_str),
+        Some("1234567")
+    );
+}
+
+/// Stand-in for `i.pximg.net`: answers with the requested path, 404 for paths containing `missing`.
+fn spawn_image_host() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+            let status = if path.contains("missing") {
+                "404 Not Found"
+            } else {
+                "200 OK"
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                path.len(),
+                path
+            )
+            .unwrap();
+        }
+    });
+
+    base
+}
+
+#[test]
+fn test_download_originals() {
+    let base = spawn_image_host();
+    let mut pixiv = PixivClient::new().unwrap();
+    pixiv.set_image_host(base.as_str());
+    let value = serde_json::from_str(include_str!("fixtures/illust_series.json")).unwrap();
+    let page: IllustrationSeries = pixiv.decode_value(value).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+
+    let files = pixiv
+        .download_originals(page.first_illust(), dir.path())
+        .expect("Every page should download.");
+
+    let names: Vec<_> = files
+        .iter()
+        .map(|file| file.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["90000001_p0.png", "90000001_p1.png"]);
+    assert_eq!(
+        std::fs::read_to_string(&files[1]).unwrap(),
+        "/img-original/img/2019/07/10/00/00/05/90000001_p1.png"
+    );
+
+    match pixiv.download_image(&format!("{}/missing.png", base), dir.path()) {
+        Err(DownloadError::Http(e)) => assert_eq!(e.status().map(|s| s.as_u16()), Some(404)),
+        other => panic!("Expected an HTTP error, got {:?}", other),
+    }
+    match pixiv.download_image(
+        &format!("{}/image.png", base),
+        &dir.path().join("does-not-exist"),
+    ) {
+        Err(DownloadError::Io(_)) => {}
+        other => panic!("Expected an I/O error, got {:?}", other),
+    }
+}
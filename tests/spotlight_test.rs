@@ -0,0 +1,80 @@
+use pixieve_rs::enums::SpotlightCategory;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::spotlight_articles::SpotlightArticles;
+use pixieve_rs::pixiv::spotlight::featured_illust_ids;
+
+use chrono::Datelike;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_spotlight_articles() {
+    let page: SpotlightArticles = strict_fixture(include_str!("fixtures/spotlight_articles.json"));
+    let article = &page.spotlight_articles()[0];
+
+    assert_eq!(page.items().len(), 2);
+    assert_eq!(article.id(), 8542);
+    assert_eq!(article.pure_title(), "夏の海を描いたイラスト特集");
+    assert_eq!(article.category(), "spotlight");
+    assert_eq!(article.subcategory_label(), "イラスト");
+    assert_eq!(
+        article.article_url().as_str(),
+        "https://www.pixivision.net/ja/a/8542"
+    );
+    assert_eq!(article.publish_date().month(), 7);
+    assert_eq!(
+        Paginated::next_url(&page)
+            .and_then(|url| url.query_pairs().find(|(k, _)| k == "offset"))
+            .map(|(_, v)| v.into_owned()),
+        Some("10".to_string())
+    );
+}
+
+#[test]
+fn test_request_spotlight_articles() {
+    let request = PixivRequestBuilder::request_spotlight_articles(SpotlightCategory::Cosplay, 10);
+    assert_eq!(request.url.path(), "/v1/spotlight/articles");
+    assert_eq!(
+        request.params.get("category").map(String::as_str),
+        Some("cosplay")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("10"));
+    assert_eq!(
+        request.params.get("filter").map(String::as_str),
+        Some("for_ios")
+    );
+
+    let categories = [
+        SpotlightCategory::All,
+        SpotlightCategory::Illustration,
+        SpotlightCategory::Manga,
+        SpotlightCategory::Cosplay,
+    ];
+    assert_eq!(
+        categories.iter().map(|c| c.as_str()).collect::<Vec<_>>(),
+        vec!["all", "illust", "manga", "cosplay"]
+    );
+}
+
+#[test]
+fn test_featured_illust_ids() {
+    assert_eq!(
+        featured_illust_ids(include_str!("fixtures/spotlight_article.html")),
+        vec![75523989, 80171413, 44298467]
+    );
+}
+
+#[test]
+fn test_featured_illust_ids_without_article() {
+    let html = r#"<div><a href="https://www.pixiv.net/artworks/1">a</a>
+        <a href="https://example.com/artworks/2">b</a>
+        <a href="https://www.pixiv.net/artworks/x">c</a>
+        <a href="/artworks/3">d</a>
+        <a href="https://www.pixiv.net/artworks/1">again</a>
+        <a href="unclosed"#;
+    assert_eq!(featured_illust_ids(html), vec![1]);
+    assert!(featured_illust_ids("").is_empty());
+}
@@ -0,0 +1,100 @@
+use pixieve_rs::enums::{ContentType, RankingMode, Visibility};
+use pixieve_rs::pixiv::result::illustration_bookmark_info_proxy::IllustBookmarkInfoProxy;
+use pixieve_rs::pixiv::result::illustration_comment::IllustrationComment;
+use pixieve_rs::pixiv::result::illustration_ranking::IllustrationRanking;
+use pixieve_rs::pixiv::result::illustration_search_proxy::IllustrationSearchProxy;
+use pixieve_rs::pixiv::result::recommended_illustration::RecommendedIllustration;
+
+#[test]
+fn test_null_caption_and_last_page() {
+    let ranking: IllustrationRanking =
+        serde_json::from_str(include_str!("fixtures/ranking_last_page.json"))
+            .expect("Failed to parse as json.");
+
+    assert_eq!(ranking.next_url(), None);
+    assert_eq!(ranking.illusts()[0].caption(), "");
+    assert!(ranking.illusts()[0].tools().is_empty());
+    assert!(ranking.illusts()[1].series().is_none());
+}
+
+#[test]
+fn test_empty_next_url_and_unknown_type() {
+    let search: IllustrationSearchProxy =
+        serde_json::from_str(include_str!("fixtures/search_empty_next_url.json"))
+            .expect("Failed to parse as json.");
+
+    assert_eq!(search.next_url(), None);
+    let illust = &search.illusts()[0];
+    assert_eq!(
+        illust.content_type(),
+        &ContentType::Unknown("illust_book".to_string())
+    );
+    assert_eq!(illust.tags()[0].translated_name(), Some("original"));
+    assert_eq!(illust.tags()[1].translated_name(), Some("landscape"));
+
+    let value = serde_json::to_value(&search).unwrap();
+    assert_eq!(value["illusts"][0]["type"], "illust_book");
+}
+
+#[test]
+fn test_unknown_restrict() {
+    let proxy: IllustBookmarkInfoProxy = serde_json::from_str(include_str!(
+        "fixtures/bookmark_detail_unknown_restrict.json"
+    ))
+    .expect("Failed to parse as json.");
+
+    assert_eq!(
+        proxy.bookmark_detail().restrict(),
+        &Visibility::Unknown("mypixiv".to_string())
+    );
+    assert_eq!(
+        proxy
+            .bookmark_detail()
+            .registered_tag_names()
+            .collect::<Vec<_>>(),
+        vec!["オリジナル"]
+    );
+}
+
+#[test]
+fn test_recommended_with_ranking_illusts() {
+    let recommended: RecommendedIllustration =
+        serde_json::from_str(include_str!("fixtures/recommended.json"))
+            .expect("Failed to parse as json.");
+
+    assert_eq!(recommended.ranking_illusts().len(), 1);
+    assert_eq!(
+        recommended
+            .next_url()
+            .and_then(|url| url.query_pairs().find(|(k, _)| k == "offset")),
+        Some(("offset".into(), "30".into()))
+    );
+}
+
+#[test]
+fn test_comments_without_total() {
+    let comments: IllustrationComment =
+        serde_json::from_str(include_str!("fixtures/comments_without_total.json"))
+            .expect("Failed to parse as json.");
+
+    assert_eq!(comments.total_comments(), None);
+    assert_eq!(comments.next_url(), None);
+    assert_eq!(comments.comments()[1].comment(), "");
+}
+
+#[test]
+fn test_unknown_enum_values() {
+    assert_eq!(
+        serde_json::from_str::<RankingMode>("\"week\"").unwrap(),
+        RankingMode::Weekly
+    );
+    assert_eq!(
+        serde_json::from_str::<RankingMode>("\"quarter\"").unwrap(),
+        RankingMode::Unknown("quarter".to_string())
+    );
+    assert_eq!(
+        serde_json::from_str::<Visibility>("\"private\"").unwrap(),
+        Visibility::Private
+    );
+    assert_eq!(ContentType::Unknown("x".to_string()).as_str(), "x");
+}
@@ -0,0 +1,44 @@
+use pixieve_rs::enums::Visibility;
+use pixieve_rs::pixiv::arg::user_bookmarks_illustration_request_arg::UserBookmarksIllustrationRequestArg;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::user_bookmarks_illustration::UserBookmarksIllustration;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_user_bookmarks_illustration() {
+    let page: UserBookmarksIllustration =
+        strict_fixture(include_str!("fixtures/user_bookmarks_illust.json"));
+
+    assert_eq!(page.illusts().len(), 2);
+    assert!(page.illusts().iter().all(|illust| illust.is_bookmarked()));
+    assert_eq!(page.next_max_bookmark_id(), Some(9117834021));
+}
+
+#[test]
+fn test_request_user_bookmarks_illustration() {
+    let request = PixivRequestBuilder::request_user_bookmarks_illustration(
+        UserBookmarksIllustrationRequestArg::new(6996493)
+            .set_restrict(Visibility::Private)
+            .set_tag("背景")
+            .set_max_bookmark_id(9117834021),
+    );
+
+    assert_eq!(request.url.path(), "/v1/user/bookmarks/illust");
+    assert_eq!(
+        request.params.get("restrict").map(String::as_str),
+        Some("private")
+    );
+    assert_eq!(request.params.get("tag").map(String::as_str), Some("背景"));
+    assert_eq!(
+        request.params.get("max_bookmark_id").map(String::as_str),
+        Some("9117834021")
+    );
+    assert!(request
+        .url
+        .query()
+        .unwrap()
+        .contains("tag=%E8%83%8C%E6%99%AF"));
+}
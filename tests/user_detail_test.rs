@@ -0,0 +1,127 @@
+use pixieve_rs::enums::Publicity;
+use pixieve_rs::pixiv::image_host::RewriteImageHost;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::user_detail::UserDetail;
+use pixieve_rs::pixiv::schema::{decode, SchemaMode};
+
+use chrono::NaiveDate;
+use url::Url;
+
+mod common;
+
+use common::strict_fixture;
+
+fn user_detail() -> UserDetail {
+    strict_fixture(include_str!("fixtures/user_detail.json"))
+}
+
+#[test]
+fn test_user_detail_null_user_fields() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/user_detail.json")).unwrap();
+    value["user"]["comment"] = serde_json::Value::Null;
+    value["user"]["is_access_blocking_user"] = serde_json::Value::Null;
+    value["user"]["is_followed"] = serde_json::Value::Null;
+    let detail: UserDetail =
+        decode(value, SchemaMode::Strict).expect("Explicit nulls should not be drift.");
+
+    assert_eq!(detail.user().comment(), None);
+    assert_eq!(detail.user().is_access_blocking_user(), None);
+    assert_eq!(detail.user().is_followed(), None);
+    let user = serde_json::to_value(detail.user()).unwrap();
+    assert!(user["comment"].is_null());
+    assert!(user.as_object().unwrap().contains_key("comment"));
+}
+
+#[test]
+fn test_user_detail_profile() {
+    let detail = user_detail();
+    let profile = detail.profile();
+
+    assert_eq!(
+        detail.user().comment(),
+        Some("Illustrations and the occasional comic.")
+    );
+    assert_eq!(profile.total_follow_users(), 215);
+    assert_eq!(profile.total_mypixiv_users(), 12);
+    assert_eq!(profile.total_works(), 158);
+    assert_eq!(profile.region(), "Tokyo, Japan");
+    assert_eq!(profile.birthday(), NaiveDate::from_ymd_opt(1994, 7, 10));
+    assert_eq!(profile.birth_day(), "07-10");
+    assert_eq!(profile.webpage(), Some("https://artist.example.com/"));
+    assert_eq!(
+        profile.parsed_twitter_url().map(String::from),
+        Some("https://twitter.com/artist_tw".to_string())
+    );
+    assert_eq!(profile.twitter_account(), "artist_tw");
+    assert_eq!(profile.pawoo_url(), None);
+    assert_eq!(profile.parsed_pawoo_url(), None);
+}
+
+#[test]
+fn test_user_detail_free_text_links() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/user_detail.json")).unwrap();
+    let cases = [
+        ("example.com", Some("https://example.com/")),
+        (
+            "http://example.com/gallery",
+            Some("http://example.com/gallery"),
+        ),
+        ("my site", None),
+        ("localhost", None),
+        ("javascript:alert(1)", None),
+        ("", None),
+    ];
+    for (webpage, parsed) in cases.iter() {
+        value["profile"]["webpage"] = (*webpage).into();
+        let detail: UserDetail = serde_json::from_value(value.clone())
+            .expect("Free text links should not fail decoding.");
+        let profile = detail.profile();
+
+        assert_eq!(
+            profile.webpage(),
+            Some(*webpage).filter(|webpage| !webpage.is_empty())
+        );
+        assert_eq!(
+            profile.parsed_webpage().as_ref().map(Url::as_str),
+            *parsed,
+            "{}",
+            webpage
+        );
+    }
+}
+
+#[test]
+fn test_user_detail_publicity_and_workspace() {
+    let detail = user_detail();
+
+    assert_eq!(detail.profile_publicity().region(), &Publicity::Public);
+    assert_eq!(detail.profile_publicity().birth_day(), &Publicity::MyPixiv);
+    assert_eq!(detail.workspace().tool(), "CLIP STUDIO PAINT");
+    assert_eq!(detail.workspace().tablet(), "Wacom Cintiq 16");
+    assert!(!detail.workspace().is_empty());
+}
+
+#[test]
+fn test_user_detail_image_host() {
+    let mut detail = user_detail();
+    detail.rewrite_image_host("https://i.pixiv.re");
+
+    assert!(detail
+        .profile()
+        .background_image_url()
+        .unwrap()
+        .starts_with("https://i.pixiv.re/"));
+}
+
+#[test]
+fn test_request_user_detail() {
+    let request = PixivRequestBuilder::request_user_detail(6996493);
+
+    assert_eq!(request.url.path(), "/v1/user/detail");
+    assert_eq!(
+        request.params.get("user_id").map(String::as_str),
+        Some("6996493")
+    );
+}
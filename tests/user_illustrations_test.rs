@@ -0,0 +1,79 @@
+use pixieve_rs::enums::ContentType;
+use pixieve_rs::errors::DecodeError;
+use pixieve_rs::pixiv::arg::user_illustrations_request_arg::UserIllustrationsRequestArg;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::user_illustrations::UserIllustrations;
+use pixieve_rs::pixiv::schema::{decode, SchemaMode};
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_user_illustrations() {
+    let page: UserIllustrations = strict_fixture(include_str!("fixtures/user_illusts.json"));
+
+    assert_eq!(page.user().id(), 6996493);
+    assert_eq!(page.items().len(), 2);
+    assert!(page
+        .illusts()
+        .iter()
+        .all(|illust| illust.user().id() == page.user().id()));
+    assert_eq!(
+        Paginated::next_url(&page)
+            .and_then(|url| url.query_pairs().find(|(k, _)| k == "offset"))
+            .map(|(_, v)| v.into_owned()),
+        Some("30".to_string())
+    );
+}
+
+#[test]
+fn test_user_illustrations_unknown_fields() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/user_illusts.json")).unwrap();
+    value["is_muted"] = serde_json::Value::from(false);
+
+    match decode::<UserIllustrations>(value.clone(), SchemaMode::Strict) {
+        Err(DecodeError::SchemaDrift(report)) => {
+            assert_eq!(report.unknown().collect::<Vec<_>>(), vec!["is_muted"])
+        }
+        other => panic!("Expected schema drift, got {:?}", other),
+    }
+    let page: UserIllustrations = decode(value, SchemaMode::Lenient).unwrap();
+    assert!(page.extra().contains_key("is_muted"));
+    assert_eq!(page.list().illusts().len(), 2);
+}
+
+#[test]
+fn test_request_user_illustrations() {
+    let request = PixivRequestBuilder::request_user_illustrations(
+        UserIllustrationsRequestArg::new(6996493)
+            .set_content_type(ContentType::Manga)
+            .set_offset(60),
+    );
+
+    assert_eq!(request.url.path(), "/v1/user/illusts");
+    assert_eq!(
+        request.params.get("user_id").map(String::as_str),
+        Some("6996493")
+    );
+    assert_eq!(
+        request.params.get("type").map(String::as_str),
+        Some("manga")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("60"));
+}
+
+#[test]
+fn test_next_page_request_keeps_query() {
+    let value = serde_json::from_str(include_str!("fixtures/user_illusts.json")).unwrap();
+    let page: UserIllustrations = decode(value, SchemaMode::Lenient).unwrap();
+    let request = PixivRequestBuilder::request_next_page(page.next_url().unwrap());
+
+    assert_eq!(request.url.path(), "/v1/user/illusts");
+    assert_eq!(
+        request.url.query(),
+        Some("user_id=6996493&type=illust&filter=for_ios&offset=30")
+    );
+}
@@ -0,0 +1,70 @@
+use pixieve_rs::enums::Visibility;
+use pixieve_rs::pixiv::arg::user_bookmark_tags_illustration_request_arg::UserBookmarkTagsIllustrationRequestArg;
+use pixieve_rs::pixiv::arg::user_following_request_arg::UserFollowingRequestArgs;
+use pixieve_rs::pixiv::image_host::RewriteImageHost;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::bookmark_tags::BookmarkTags;
+use pixieve_rs::pixiv::result::user_previews::UserPreviews;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_bookmark_tags() {
+    let tags: BookmarkTags = strict_fixture(include_str!("fixtures/bookmark_tags.json"));
+
+    assert_eq!(
+        tags.items()
+            .iter()
+            .map(|tag| (tag.name(), tag.count()))
+            .collect::<Vec<_>>(),
+        vec![("背景", 42), ("オリジナル", 17), ("参考", 3)]
+    );
+    assert!(tags.next_url().is_some());
+}
+
+#[test]
+fn test_user_previews() {
+    let mut previews: UserPreviews = strict_fixture(include_str!("fixtures/user_previews.json"));
+
+    assert_eq!(previews.items().len(), 2);
+    assert_eq!(previews.user_previews()[0].user().id(), 6996493);
+    assert_eq!(previews.user_previews()[0].illusts().len(), 1);
+    assert!(previews.user_previews()[0].novels().is_empty());
+    assert!(previews.user_previews()[1].is_muted());
+    assert_eq!(previews.next_url(), None);
+
+    previews.rewrite_image_host("https://i.pixiv.re");
+    assert!(previews.user_previews()[1].illusts()[0]
+        .image_urls()
+        .medium
+        .as_deref()
+        .unwrap()
+        .starts_with("https://i.pixiv.re/"));
+}
+
+#[test]
+fn test_request_user_listings() {
+    let request = PixivRequestBuilder::request_user_bookmark_tags_illustration(
+        UserBookmarkTagsIllustrationRequestArg {
+            restrict: Visibility::Private,
+            offset: Some(30),
+        },
+    );
+    assert_eq!(request.url.path(), "/v1/user/bookmark-tags/illust");
+    assert_eq!(
+        request.params.get("restrict").map(String::as_str),
+        Some("private")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("30"));
+
+    let request =
+        PixivRequestBuilder::request_user_following(UserFollowingRequestArgs::new(6996493));
+    assert_eq!(request.url.path(), "/v1/user/following");
+    assert_eq!(
+        request.params.get("user_id").map(String::as_str),
+        Some("6996493")
+    );
+}
@@ -0,0 +1,53 @@
+use pixieve_rs::enums::{Duration, SearchSort};
+use pixieve_rs::pixiv::arg::user_search_request_arg::UserSearchRequestArg;
+use pixieve_rs::pixiv::pagination::Paginated;
+use pixieve_rs::pixiv::request_builder::PixivRequestBuilder;
+use pixieve_rs::pixiv::result::user_previews::UserPreviews;
+
+mod common;
+
+use common::strict_fixture;
+
+#[test]
+fn test_request_user_search() {
+    let request = PixivRequestBuilder::request_user_search(
+        UserSearchRequestArg::new("artist")
+            .set_sort(SearchSort::DateAscending)
+            .set_duration(Duration::LastWeek)
+            .set_offset(30),
+    );
+
+    assert_eq!(request.url.path(), "/v1/search/user");
+    assert_eq!(
+        request.params.get("word").map(String::as_str),
+        Some("artist")
+    );
+    assert_eq!(
+        request.params.get("sort").map(String::as_str),
+        Some("date_asc")
+    );
+    assert_eq!(
+        request.params.get("duration").map(String::as_str),
+        Some("within_last_week")
+    );
+    assert_eq!(request.params.get("offset").map(String::as_str), Some("30"));
+
+    let request = PixivRequestBuilder::request_user_search(UserSearchRequestArg::new("artist"));
+    assert!(!request.params.contains_key("duration"));
+    assert!(!request.params.contains_key("offset"));
+}
+
+#[test]
+fn test_user_search_result() {
+    let users: UserPreviews = strict_fixture(include_str!("fixtures/user_search.json"));
+
+    assert_eq!(
+        users
+            .items()
+            .iter()
+            .map(|preview| preview.user().account())
+            .collect::<Vec<_>>(),
+        vec!["artist_account", "yoru_0"]
+    );
+    assert!(Paginated::next_url(&users).is_some());
+}